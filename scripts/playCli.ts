@@ -0,0 +1,244 @@
+/**
+ * Headless play harness — drives a scenario from the terminal.
+ *
+ * Run with: npx tsx scripts/playCli.ts [scenario.json]
+ *
+ * Loads and validates a scenario (default: interactive_arena). A `tiled_map`
+ * reference is read from public/ and its terrain replaces the JSON map
+ * block, as loadScenarioFromUrl does in the browser. Renders the grid with
+ * battleToAscii, and reads one command per line from stdin for
+ * player-team units. AI-team turns use getAiCommand with the scenario's
+ * content packs (loaded from disk the way scenario tests load them), and
+ * reactions are detected with detectCommandReactions like the store does.
+ * Unlike the store there is no reaction prompt: every reaction is taken,
+ * player-team ones included. All gameplay goes through applyCommand — this
+ * file only parses input and prints results, so it's usable for manual
+ * testing and for piping a command script in a CI smoke test:
+ *
+ *   printf 'move 2 5\nend\n' | npx tsx scripts/playCli.ts
+ *
+ * Commands:
+ *   move <x> <y>            move the active unit
+ *   strike <target> [weapon] strike a unit (optional weapon index)
+ *   end                     end the active unit's turn
+ *   map                     reprint the grid
+ *   units                   list units with hp and position
+ *   quit                    exit
+ */
+
+import { readFileSync } from "fs";
+import { dirname, isAbsolute, resolve } from "path";
+import { createInterface } from "readline";
+import { battleStateFromScenario, validateScenario } from "../src/io/scenarioLoader";
+import { ContentContext, resolveContentContextSync } from "../src/io/contentPackLoader";
+import { applyTiledTerrain } from "../src/io/mapDataBridge";
+import type { ResolvedTiledMap, TiledMap, TiledTileset } from "../src/io/tiledTypes";
+import {
+  buildOrchestratorConfig,
+  checkBattleEnd,
+  getAiCommand,
+  isAiUnit,
+  materializeRawCommand,
+} from "../src/io/battleOrchestrator";
+import { type ReactionTrigger, detectCommandReactions, reactionCommand } from "../src/engine/reactions";
+import { applyCommand, ReductionError } from "../src/engine/reducer";
+import { DeterministicRNG } from "../src/engine/rng";
+import { BattleState, activeUnitId } from "../src/engine/state";
+import { RawCommand } from "../src/engine/commands";
+import { battleToAscii } from "../src/grid/ascii";
+
+const DEFAULT_SCENARIO = "scenarios/smoke/interactive_arena.json";
+/** Safety cap on consecutive AI commands — a policy that never ends its turn would otherwise spin. */
+const MAX_AI_COMMANDS = 200;
+
+function printEvents(events: Record<string, unknown>[]): void {
+  for (const ev of events) {
+    console.log(`  ${String(ev["type"]).padEnd(20)} ${JSON.stringify(ev["payload"] ?? {})}`);
+  }
+}
+
+function printUnits(state: BattleState): void {
+  for (const u of Object.values(state.units).sort((a, b) => a.unitId.localeCompare(b.unitId))) {
+    console.log(`  ${u.unitId.padEnd(20)} ${u.team.padEnd(8)} hp ${u.hp}/${u.maxHp}  (${u.x},${u.y})`);
+  }
+}
+
+/** Maps "/..." URL paths to public/ — where the browser is served from. */
+function publicPath(urlPath: string): string {
+  return resolve(process.cwd(), "public", urlPath.replace(/^\//, ""));
+}
+
+/** Disk counterpart of tiledLoader.loadTiledMap: external .tsj tilesets resolve next to the map. */
+function readTiledMap(urlPath: string): ResolvedTiledMap {
+  const mapFile = publicPath(urlPath);
+  const raw = JSON.parse(readFileSync(mapFile, "utf-8")) as TiledMap;
+  const tilesets = raw.tilesets.map((ref) => {
+    if (!("source" in ref) || "image" in ref) return ref as TiledTileset;
+    const tsj = JSON.parse(readFileSync(resolve(dirname(mapFile), ref.source), "utf-8")) as Omit<TiledTileset, "firstgid">;
+    return { ...tsj, firstgid: ref.firstgid };
+  });
+  tilesets.sort((a, b) => a.firstgid - b.firstgid);
+  return { ...raw, tilesets };
+}
+
+/** Same path rules as scenarioTestRunner: "/..." is served from public/, anything else is scenario-relative. */
+function loadContentContext(scenario: Record<string, unknown>, scenarioPath: string): ContentContext {
+  const scenarioDir = dirname(resolve(scenarioPath));
+  const packDataList = ((scenario["content_packs"] as string[]) ?? []).map((packPath) => {
+    const absolutePackPath = packPath.startsWith("/")
+      ? publicPath(packPath)
+      : isAbsolute(packPath) ? packPath : resolve(scenarioDir, packPath);
+    return JSON.parse(readFileSync(absolutePackPath, "utf-8")) as Record<string, unknown>;
+  });
+  const enginePhase = (scenario["engine_phase"] as number) ?? 7;
+  return resolveContentContextSync(scenario, enginePhase, packDataList);
+}
+
+/** Integer argument, or null if `arg` isn't one ("1.5", "x", ""). */
+function intArg(arg: string): number | null {
+  const n = Number(arg);
+  return arg.trim() !== "" && Number.isInteger(n) ? n : null;
+}
+
+function parseLine(line: string, actor: string): RawCommand | string | null {
+  const [verb, ...args] = line.trim().split(/\s+/);
+  switch (verb) {
+    case "move": {
+      const x = args.length === 2 ? intArg(args[0]) : null;
+      const y = args.length === 2 ? intArg(args[1]) : null;
+      if (x === null || y === null) return "usage: move <x> <y>";
+      return { type: "move", actor, x, y };
+    }
+    case "strike": {
+      if (args.length < 1 || args.length > 2) return "usage: strike <target> [weapon]";
+      const weaponIndex = args[1] !== undefined ? intArg(args[1]) : undefined;
+      if (weaponIndex === null) return "usage: strike <target> [weapon]";
+      return {
+        type: "strike",
+        actor,
+        target: args[0],
+        ...(weaponIndex !== undefined && { weapon_index: weaponIndex }),
+      };
+    }
+    case "end":
+      return { type: "end_turn", actor };
+    default:
+      return null;
+  }
+}
+
+async function main() {
+  const path = process.argv[2] ?? DEFAULT_SCENARIO;
+  let scenario = JSON.parse(readFileSync(path, "utf-8")) as Record<string, unknown>;
+  if (typeof scenario["tiled_map"] === "string" && scenario["tiled_map"]) {
+    scenario = applyTiledTerrain(scenario, readTiledMap(scenario["tiled_map"]));
+  }
+  validateScenario(scenario);
+  const config = buildOrchestratorConfig(scenario);
+  const contentContext = loadContentContext(scenario, path);
+  let state = battleStateFromScenario(scenario);
+  let rng = new DeterministicRNG(state.seed);
+  let reactionQueue: ReactionTrigger[] = [];
+
+  /**
+   * Apply one command; on rejection, restore the RNG position (same rule as
+   * battleStore.dispatchCommand). On success, queue the reactions it
+   * provoked ahead of any already pending, so cascades resolve first.
+   */
+  const apply = (cmd: RawCommand): boolean => {
+    const capturedCount = rng.callCount;
+    try {
+      const [next, events] = applyCommand(state, cmd, rng);
+      state = next;
+      printEvents(events);
+      if (!checkBattleEnd(state, config).ended) {
+        reactionQueue = [...detectCommandReactions(state, cmd as Record<string, unknown>, events), ...reactionQueue];
+      }
+      return true;
+    } catch (err) {
+      if (!(err instanceof ReductionError)) throw err;
+      rng = new DeterministicRNG(state.seed, capturedCount);
+      console.log(`  rejected: ${err.message}`);
+      return false;
+    }
+  };
+
+  /** Apply a command, then take every reaction it provoked. */
+  const dispatch = (cmd: RawCommand): boolean => {
+    if (!apply(cmd)) return false;
+    while (reactionQueue.length > 0 && !checkBattleEnd(state, config).ended) {
+      const [trigger, ...rest] = reactionQueue;
+      reactionQueue = rest;
+      const reaction = reactionCommand(trigger);
+      if (!reaction) continue;
+      console.log(`${trigger.reactorId} (reaction): ${trigger.reactionType}`);
+      apply(reaction);
+    }
+    reactionQueue = [];
+    return true;
+  };
+
+  /** Run AI turns until a player unit is active or the battle ends. Returns true if ended. */
+  const runAi = (): boolean => {
+    let steps = 0;
+    while (isAiUnit(state, config)) {
+      if (checkBattleEnd(state, config).ended) return true;
+      if (steps++ >= MAX_AI_COMMANDS) throw new Error("AI command cap reached");
+      let raw = getAiCommand(state, config.enemyPolicy, contentContext);
+      if (raw["content_entry_id"]) {
+        try {
+          raw = materializeRawCommand(raw, contentContext);
+        } catch (err) {
+          console.log(`  rejected: ${(err as Error).message}`);
+          raw = { type: "end_turn", actor: activeUnitId(state) };
+        }
+      }
+      const cmd = raw as unknown as RawCommand;
+      console.log(`${activeUnitId(state)} (ai): ${cmd.type}`);
+      if (!dispatch(cmd)) dispatch({ type: "end_turn", actor: activeUnitId(state) });
+    }
+    return checkBattleEnd(state, config).ended;
+  };
+
+  const prompt = () => {
+    console.log(`\nround ${state.roundNumber} — ${activeUnitId(state)} (${state.units[activeUnitId(state)].actionsRemaining} actions)`);
+    process.stdout.write("> ");
+  };
+
+  console.log(battleToAscii(state));
+  if (runAi()) {
+    console.log(`battle over: ${checkBattleEnd(state, config).outcome}`);
+    return;
+  }
+  prompt();
+
+  const rl = createInterface({ input: process.stdin });
+  for await (const line of rl) {
+    const trimmed = line.trim();
+    if (trimmed === "quit") break;
+    if (trimmed === "map") {
+      console.log(battleToAscii(state));
+    } else if (trimmed === "units") {
+      printUnits(state);
+    } else if (trimmed !== "") {
+      const cmd = parseLine(trimmed, activeUnitId(state));
+      if (cmd === null) console.log(`unknown command: ${trimmed}`);
+      else if (typeof cmd === "string") console.log(cmd);
+      else if (dispatch(cmd)) {
+        const ended = checkBattleEnd(state, config).ended || runAi();
+        console.log(battleToAscii(state));
+        if (ended) {
+          console.log(`battle over: ${checkBattleEnd(state, config).outcome}`);
+          break;
+        }
+      }
+    }
+    prompt();
+  }
+  rl.close();
+}
+
+main().catch((err) => {
+  console.error(err);
+  process.exit(1);
+});
@@ -0,0 +1,132 @@
+/**
+ * Randomized invariant tests for move / end_turn.
+ *
+ * Generates seeded random maps and command sequences with the engine's own
+ * DeterministicRNG (so failures reproduce from the seed in the test name),
+ * applies them through applyCommand, and checks after every step that:
+ * - every unit is in bounds, and no living unit stands on a blocked tile
+ * - no two living units share a tile
+ * - actionsRemaining never goes negative
+ * - roundNumber and eventSequence never decrease
+ * - applyCommand never mutates its input state, accepted or rejected (deepClone invariant)
+ *
+ * Most generated moves target reachable tiles; roughly a quarter are
+ * deliberately invalid (off-map, blocked, or out of reach).
+ */
+
+import { describe, it, expect } from "vitest";
+import { createTestBattle, createTestUnit } from "../test-utils/fixtures";
+import { applyCommand, ReductionError } from "./reducer";
+import { DeterministicRNG } from "./rng";
+import { BattleState, UnitState, activeUnit, activeUnitId, unitAlive } from "./state";
+import { RawCommand } from "./commands";
+import { reachableTiles } from "../grid/movement";
+
+const SEEDS = Array.from({ length: 25 }, (_, i) => 1000 + i);
+const STEPS = 40;
+
+function pick<T>(gen: DeterministicRNG, items: T[]): T {
+  return items[gen.randint(0, items.length - 1).value];
+}
+
+function randomBattle(gen: DeterministicRNG, seed: number): BattleState {
+  const width = gen.randint(6, 14).value;
+  const height = gen.randint(6, 14).value;
+  const blocked: Array<[number, number]> = [];
+  const open: Array<[number, number]> = [];
+  for (let y = 0; y < height; y++) {
+    for (let x = 0; x < width; x++) {
+      if (gen.randint(1, 100).value <= 15) blocked.push([x, y]);
+      else open.push([x, y]);
+    }
+  }
+  const units: Record<string, UnitState> = {};
+  const unitCount = gen.randint(2, 4).value;
+  for (let i = 0; i < unitCount && open.length > 0; i++) {
+    const [x, y] = open.splice(gen.randint(0, open.length - 1).value, 1)[0];
+    const unitId = `u${i}`;
+    units[unitId] = createTestUnit({
+      unitId,
+      team: i % 2 ? "enemy" : "pc",
+      x,
+      y,
+      speed: gen.randint(2, 6).value,
+      initiative: 20 - i,
+    });
+  }
+  return createTestBattle({
+    seed,
+    units,
+    battleMap: { width, height, blocked },
+  });
+}
+
+function randomCommand(gen: DeterministicRNG, state: BattleState): RawCommand {
+  const actor = activeUnitId(state);
+  const roll = gen.randint(1, 100).value;
+  if (roll <= 20) return { type: "end_turn", actor };
+
+  const { width, height, blocked } = state.battleMap;
+  if (roll <= 30) {
+    // Off-map
+    return { type: "move", actor, x: pick(gen, [-1, width, width + 3]), y: gen.randint(0, height - 1).value };
+  }
+  if (roll <= 38 && blocked.length > 0) {
+    const [x, y] = pick(gen, blocked);
+    return { type: "move", actor, x, y };
+  }
+  if (roll <= 45) {
+    // Arbitrary in-bounds tile — often out of reach
+    return { type: "move", actor, x: gen.randint(0, width - 1).value, y: gen.randint(0, height - 1).value };
+  }
+  const reach = [...reachableTiles(state, actor)];
+  if (reach.length === 0) return { type: "end_turn", actor };
+  const [x, y] = pick(gen, reach).split(",").map(Number);
+  return { type: "move", actor, x, y };
+}
+
+function assertInvariants(state: BattleState): void {
+  const { width, height } = state.battleMap;
+  const blocked = new Set(state.battleMap.blocked.map(([x, y]) => `${x},${y}`));
+  const seen = new Set<string>();
+  for (const u of Object.values(state.units)) {
+    expect(u.x >= 0 && u.x < width && u.y >= 0 && u.y < height).toBe(true);
+    expect(u.actionsRemaining).toBeGreaterThanOrEqual(0);
+    if (!unitAlive(u)) continue;
+    const key = `${u.x},${u.y}`;
+    expect(blocked.has(key)).toBe(false);
+    expect(seen.has(key)).toBe(false);
+    seen.add(key);
+  }
+}
+
+describe("move / end_turn invariants (seeded random sequences)", () => {
+  for (const seed of SEEDS) {
+    it(`seed ${seed}`, () => {
+      const gen = new DeterministicRNG(seed);
+      const rng = new DeterministicRNG(seed);
+      let state = randomBattle(gen, seed);
+      assertInvariants(state);
+
+      for (let step = 0; step < STEPS; step++) {
+        const cmd = randomCommand(gen, state);
+        const prev = state;
+        const before = JSON.stringify(prev);
+        try {
+          const [next] = applyCommand(prev, cmd, rng);
+          expect(next.roundNumber).toBeGreaterThanOrEqual(prev.roundNumber);
+          expect(next.eventSequence).toBeGreaterThanOrEqual(prev.eventSequence);
+          if (cmd.type === "move") {
+            expect(next.units[cmd.actor!].actionsRemaining).toBe(activeUnit(prev).actionsRemaining - 1);
+          }
+          state = next;
+        } catch (err) {
+          if (!(err instanceof ReductionError)) throw err;
+        }
+        // The input state is never mutated, whether the command succeeded or threw
+        expect(JSON.stringify(prev)).toBe(before);
+        assertInvariants(state);
+      }
+    });
+  }
+});
@@ -1,5 +1,10 @@
 import { describe, it, expect } from "vitest";
-import { detectMoveReactions, detectDamageReactions } from "./reactions";
+import {
+  detectMoveReactions,
+  detectDamageReactions,
+  detectCommandReactions,
+  reactionCommand,
+} from "./reactions";
 import { applyCommand } from "./reducer";
 import {
   createTestUnit,
@@ -251,3 +256,35 @@ describe("turn reset", () => {
     expect(next2.units["su"].shieldRaised).toBe(false);
   });
 });
+
+describe("detectCommandReactions", () => {
+  const battle = createTestBattle({
+    units: { shield: createShieldUnit({ unitId: "shield", shieldRaised: true }) },
+  });
+
+  it("reads the damaged target from a strike event", () => {
+    const events = [{ type: "strike", payload: { target: "shield", damage: { total: 8, damage_type: "slashing" } } }];
+    const triggers = detectCommandReactions(battle, { type: "strike", actor: "orc" }, events);
+    expect(triggers.map((t) => t.reactionType)).toEqual(["shield_block"]);
+    expect(triggers[0].data?.["damageAmount"]).toBe(8);
+  });
+
+  it("fans out over area resolutions and ignores commands that can't provoke", () => {
+    const events = [{
+      type: "area_save_damage",
+      payload: { resolutions: [{ target: "shield", damage: { applied_total: 6, damage_type: "bludgeoning" } }] },
+    }];
+    expect(detectCommandReactions(battle, { type: "area_save_damage" }, events)).toHaveLength(1);
+    expect(detectCommandReactions(battle, { type: "end_turn" }, [])).toEqual([]);
+  });
+});
+
+describe("reactionCommand", () => {
+  it("maps each reaction type to the command that resolves it", () => {
+    expect(reactionCommand({ reactorId: "r", reactionType: "attack_of_opportunity", provokerId: "m" }))
+      .toEqual({ type: "reaction_strike", actor: "r", target: "m" });
+    expect(reactionCommand({ reactorId: "s", reactionType: "shield_block", provokerId: "s", data: { damageAmount: 7 } }))
+      .toEqual({ type: "shield_block", actor: "s", damage_amount: 7 });
+    expect(reactionCommand({ reactorId: "x", reactionType: "unknown", provokerId: "y" })).toBeNull();
+  });
+});
@@ -1,9 +1,9 @@
 /**
  * Pure trigger detection functions for the reaction system.
  *
- * These are called by the store layer after each command to check if any
- * reactions should be queued. Triggers are returned sorted by unitId for
- * deterministic ordering.
+ * These are called after each command — by the store layer, the headless
+ * battle simulation, and the CLI harness — to check if any reactions should
+ * be queued. Triggers are returned sorted by unitId for deterministic ordering.
  *
  * Reaction types:
  *   - attack_of_opportunity: triggers when enemy uses move action in reactor's melee reach
@@ -12,7 +12,9 @@
  */
 
 import { BattleState, unitAlive, resolveWeapon } from "./state";
+import { RawCommand } from "./commands";
 import { hasLineOfSight } from "../grid/los";
+import { chebyshevDistance } from "../grid/movement";
 
 export interface ReactionTrigger {
   /** The unit that may react. */
@@ -58,7 +60,7 @@ export function detectMoveReactions(
     const weapon = resolveWeapon(unit);
     if (weapon.type !== "melee") continue;
     const reach = weapon.reach ?? 1;
-    const dist = Math.max(Math.abs(unit.x - fromX), Math.abs(unit.y - fromY));
+    const dist = chebyshevDistance(unit.x, unit.y, fromX, fromY);
     if (dist > reach) continue;
 
     // Reactor must have LOS to the mover (at current position)
@@ -120,3 +122,75 @@ function isPhysicalDamage(damageType: string): boolean {
   const physical = ["physical", "slashing", "piercing", "bludgeoning"];
   return physical.includes(damageType.toLowerCase());
 }
+
+/**
+ * Every reaction trigger provoked by one successfully applied command. `cmd`
+ * is the command as handed to applyCommand (after materialization — an area
+ * spell arrives as area_save_damage) and `events` is what it returned.
+ * Moves check for AoO / Reactive Strike from the mover's previous tile;
+ * damaging commands check each damaged target for Shield Block.
+ */
+export function detectCommandReactions(
+  state: BattleState,
+  cmd: Record<string, unknown>,
+  events: Record<string, unknown>[],
+): ReactionTrigger[] {
+  const cmdType = String(cmd["type"] ?? "");
+
+  if (cmdType === "move") {
+    // fromX/fromY from the move event
+    const moveEvent = events.find((e) => e["type"] === "move");
+    if (!moveEvent) return [];
+    const from = (moveEvent["payload"] as Record<string, unknown>)["from"] as number[];
+    if (!from) return [];
+    return detectMoveReactions(state, String(cmd["actor"]), from[0], from[1]);
+  }
+
+  if (cmdType === "strike" || cmdType === "reaction_strike" ||
+      cmdType === "cast_spell" || cmdType === "save_damage") {
+    const damageEvent = events.find((e) =>
+      e["type"] === cmdType || e["type"] === "strike" || e["type"] === "reaction_strike");
+    if (!damageEvent) return [];
+    const payload = damageEvent["payload"] as Record<string, unknown>;
+    const dmg = payload["damage"] as Record<string, unknown> | null;
+    if (!dmg || Number(dmg["applied_total"] ?? dmg["total"] ?? 0) <= 0) return [];
+    const targetId = String(payload["target"]);
+    const damageType = String(dmg["damage_type"] ?? "physical");
+    const total = Number(dmg["applied_total"] ?? dmg["total"]);
+    return detectDamageReactions(state, targetId, total, damageType);
+  }
+
+  if (cmdType === "area_save_damage") {
+    const areaEvent = events.find((e) => e["type"] === "area_save_damage");
+    if (!areaEvent) return [];
+    const resolutions = (areaEvent["payload"] as Record<string, unknown>)["resolutions"] as Record<string, unknown>[];
+    const triggers: ReactionTrigger[] = [];
+    for (const res of resolutions ?? []) {
+      const dmg = res["damage"] as Record<string, unknown> | null;
+      if (dmg && Number(dmg["applied_total"] ?? 0) > 0) {
+        const targetId = String(res["target"]);
+        const damageType = String(dmg["damage_type"] ?? "physical");
+        triggers.push(...detectDamageReactions(state, targetId, Number(dmg["applied_total"]), damageType));
+      }
+    }
+    return triggers;
+  }
+
+  return [];
+}
+
+/** The command that carries out an accepted reaction, or null for an unrecognized type. */
+export function reactionCommand(trigger: ReactionTrigger): RawCommand | null {
+  if (trigger.reactionType === "attack_of_opportunity" ||
+      trigger.reactionType === "reactive_strike") {
+    return { type: "reaction_strike", actor: trigger.reactorId, target: trigger.provokerId };
+  }
+  if (trigger.reactionType === "shield_block") {
+    return {
+      type: "shield_block",
+      actor: trigger.reactorId,
+      damage_amount: Number(trigger.data?.["damageAmount"] ?? 0),
+    };
+  }
+  return null;
+}
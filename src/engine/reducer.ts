@@ -9,8 +9,8 @@ import { LifecycleEvent, onApply, processTiming } from "../effects/lifecycle";
 import { conePoints, linePoints, radiusPoints } from "../grid/areas";
 import { adjustCoverForMelee, coverAcBonusFromGrade, coverGradeForUnits, hasTileLineOfEffect } from "../grid/loe";
 import { hasLineOfSight } from "../grid/los";
-import { inBounds, isBlocked, isOccupied, tilesFromFeet } from "../grid/map";
-import { reachableTiles } from "../grid/movement";
+import { inBounds, isBlocked, isOccupied, mapTiles, tilesFromFeet } from "../grid/map";
+import { chebyshevDistance, manhattanDistance, reachableTiles } from "../grid/movement";
 import { applyCondition, clearCondition, conditionIsImmune, normalizeConditionName } from "../rules/conditions";
 import { applyDamageModifiers, applyDamageToPool, parseFormula, rollDamage, rollTraitBonusDice } from "../rules/damage";
 import { isAgile, mapPenalty as traitMapPenalty, volleyPenalty, deadlyDice, fatalDice, thrownRange } from "./traits";
@@ -245,8 +245,8 @@ function nearestEnemyUnitId(state: BattleState, actorId: string): string | null
   );
   if (enemies.length === 0) return null;
   const sorted = enemies.sort((a, b) => {
-    const da = Math.max(Math.abs(a.x - actor.x), Math.abs(a.y - actor.y));
-    const db = Math.max(Math.abs(b.x - actor.x), Math.abs(b.y - actor.y));
+    const da = chebyshevDistance(a.x, a.y, actor.x, actor.y);
+    const db = chebyshevDistance(b.x, b.y, actor.x, actor.y);
     if (da !== db) return da - db;
     return a.unitId.localeCompare(b.unitId);
   });
@@ -277,15 +277,10 @@ function nearestOpenTile(
   x: number,
   y: number,
 ): [number, number] | null {
-  const tiles: Array<[number, number]> = [];
-  for (let tx = 0; tx < state.battleMap.width; tx++) {
-    for (let ty = 0; ty < state.battleMap.height; ty++) {
-      tiles.push([tx, ty]);
-    }
-  }
+  const tiles = [...mapTiles(state.battleMap)];
   tiles.sort((a, b) => {
-    const da = Math.abs(a[0] - x) + Math.abs(a[1] - y);
-    const db = Math.abs(b[0] - x) + Math.abs(b[1] - y);
+    const da = manhattanDistance(a[0], a[1], x, y);
+    const db = manhattanDistance(b[0], b[1], x, y);
     if (da !== db) return da - db;
     if (a[1] !== b[1]) return a[1] - b[1];
     return a[0] - b[0];
@@ -785,7 +780,7 @@ export function applyCommand(
       throw new ReductionError(String((err as Error).message));
     }
 
-    const dist = Math.max(Math.abs(actor.x - target.x), Math.abs(actor.y - target.y));
+    const dist = chebyshevDistance(actor.x, actor.y, target.x, target.y);
 
     // Determine effective weapon type (thrown melee can act as ranged)
     const reach = weapon.reach ?? 1;
@@ -1101,7 +1096,7 @@ export function applyCommand(
       throw new ReductionError("reaction strikes must use a melee weapon");
     }
     const reach = weapon.reach ?? 1;
-    const dist = Math.max(Math.abs(actor.x - target.x), Math.abs(actor.y - target.y));
+    const dist = chebyshevDistance(actor.x, actor.y, target.x, target.y);
     if (dist > reach) {
       throw new ReductionError(`target ${targetId} is out of reach for reaction strike`);
     }
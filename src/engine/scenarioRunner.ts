@@ -12,6 +12,7 @@ import { BattleState, activeUnitId, unitAlive } from "./state";
 import { buildCommandAuthoringCatalog } from "../io/commandAuthoring";
 import { ContentContext } from "../io/contentPackLoader";
 import { replayHash } from "../io/eventLog";
+import { chebyshevDistance } from "../grid/movement";
 
 export const DEFAULT_ENGINE_PHASE = 7;
 
@@ -390,7 +391,7 @@ function enemyCandidatesForActor(state: BattleState, actorId: string): Array<[st
   );
   if (enemies.length === 0) return [];
   return enemies
-    .map((u): [string, number] => [u.unitId, Math.max(Math.abs(u.x - actor.x), Math.abs(u.y - actor.y))])
+    .map((u): [string, number] => [u.unitId, chebyshevDistance(u.x, u.y, actor.x, actor.y)])
     .sort(([idA, dA], [idB, dB]) => {
       const dd = dA - dB;
       if (dd !== 0) return dd;
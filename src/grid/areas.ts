@@ -2,6 +2,8 @@
  * Area targeting helpers.
  */
 
+import { gridDistance, manhattanDistance } from "./movement";
+
 export function radiusPoints(
   cx: number,
   cy: number,
@@ -10,7 +12,7 @@ export function radiusPoints(
   const points: Array<[number, number]> = [];
   for (let x = cx - radius; x <= cx + radius; x++) {
     for (let y = cy - radius; y <= cy + radius; y++) {
-      if (Math.abs(x - cx) + Math.abs(y - cy) <= radius) {
+      if (manhattanDistance(x, y, cx, cy) <= radius) {
         points.push([x, y]);
       }
     }
@@ -63,7 +65,7 @@ export function conePoints(
   const dirY = facingY - originY;
   if (dirX === 0 && dirY === 0) return [[originX, originY]];
 
-  const norm = Math.hypot(dirX, dirY);
+  const norm = gridDistance(originX, originY, facingX, facingY, "euclidean");
   const unitX = dirX / norm;
   const unitY = dirY / norm;
   const minDot = Math.cos((45.0 * Math.PI) / 180.0);
@@ -73,7 +75,7 @@ export function conePoints(
     for (let y = originY - length; y <= originY + length; y++) {
       const vecX = x - originX;
       const vecY = y - originY;
-      const dist = Math.hypot(vecX, vecY);
+      const dist = gridDistance(originX, originY, x, y, "euclidean");
       if (dist === 0) {
         points.push([x, y]);
         continue;
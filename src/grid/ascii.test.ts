@@ -0,0 +1,78 @@
+import { describe, it, expect } from "vitest";
+import { readFileSync } from "fs";
+import { createTestBattle, createTestMap, createTestUnit } from "../test-utils/fixtures";
+import { battleStateFromScenario } from "../io/scenarioLoader";
+import { battleToAscii, mapFromAscii, mapToAscii } from "./ascii";
+
+describe("mapToAscii", () => {
+  it("renders blocked, hazard, and difficult tiles with blocked taking precedence", () => {
+    const map = createTestMap({
+      width: 4,
+      height: 2,
+      blocked: [[0, 0]],
+      moveCost: { "1,0": 2, "0,0": 2, "3,1": 1 },
+      hazards: [{
+        id: "fire", damageType: "fire", damagePerTurn: 2, dc: 15, saveType: "Reflex",
+        tiles: [[2, 0], [0, 0]],
+      }],
+    });
+    expect(mapToAscii(map)).toBe("#:^.\n....");
+  });
+});
+
+describe("battleToAscii", () => {
+  it("draws living units by team and skips dead ones", () => {
+    const state = createTestBattle({
+      battleMap: createTestMap({ width: 3, height: 2 }),
+      units: {
+        hero: createTestUnit({ unitId: "hero", team: "pc", x: 0, y: 0 }),
+        orc: createTestUnit({ unitId: "orc", team: "enemy", x: 2, y: 1 }),
+        corpse: createTestUnit({ unitId: "corpse", team: "enemy", x: 1, y: 0, hp: 0 }),
+        odd: createTestUnit({ unitId: "odd", team: "neutral", x: 1, y: 1 }),
+      },
+    });
+    expect(battleToAscii(state)).toBe("@..\n.?e");
+  });
+
+  it("matches a snapshot of the interactive arena smoke scenario", () => {
+    const raw = JSON.parse(readFileSync("scenarios/smoke/interactive_arena.json", "utf-8"));
+    const state = battleStateFromScenario(raw);
+    expect(battleToAscii(state)).toBe([
+      "..........",
+      "........e.",
+      ".......e..",
+      "....##....",
+      "....##....",
+      "..........",
+      ".@........",
+      "..@.......",
+    ].join("\n"));
+  });
+});
+
+describe("mapFromAscii", () => {
+  const grid = ["..#.", ".:#.", "...."].join("\n");
+
+  it("parses floor, blocked, and difficult tiles", () => {
+    expect(mapFromAscii(grid)).toEqual({
+      width: 4,
+      height: 3,
+      blocked: [[2, 0], [2, 1]],
+      moveCost: { "1,1": 2 },
+    });
+  });
+
+  it("round-trips through mapToAscii", () => {
+    expect(mapToAscii(mapFromAscii(grid))).toBe(grid);
+  });
+
+  it("accepts a trailing newline and CRLF line endings", () => {
+    expect(mapFromAscii(grid + "\n")).toEqual(mapFromAscii(grid));
+    expect(mapFromAscii(grid.replace(/\n/g, "\r\n") + "\r\n")).toEqual(mapFromAscii(grid));
+  });
+
+  it("names the offending glyph and its position", () => {
+    expect(() => mapFromAscii("...\n.~.")).toThrow('unknown ascii map glyph "~" at (1,1)');
+    expect(() => mapFromAscii("...\n..")).toThrow(/row 1 has width 2/);
+  });
+});
@@ -0,0 +1,98 @@
+/**
+ * ASCII rendering of the battle grid.
+ *
+ * One character per tile, rows joined by "\n" (y = 0 first). Intended for
+ * debugging, test fixtures, and terminal output — not used by the PixiJS
+ * renderer. `mapFromAscii` parses the terrain glyphs back into a MapState.
+ */
+
+import { BattleState, MapState, unitAlive } from "../engine/state";
+
+export const ASCII_FLOOR = ".";
+export const ASCII_BLOCKED = "#";
+/** moveCost > 1 (PF2e difficult terrain). */
+export const ASCII_DIFFICULT = ":";
+/** Tile covered by at least one hazard zone. */
+export const ASCII_HAZARD = "^";
+
+/** Glyphs for living units by team. Unknown teams render as "?". */
+export const ASCII_TEAM_GLYPHS: Readonly<Record<string, string>> = {
+  pc: "@",
+  enemy: "e",
+  hazard: "h",
+};
+
+/**
+ * Render terrain only. Precedence per tile: blocked > hazard > difficult > floor.
+ */
+export function mapToAscii(map: MapState): string {
+  const blocked = new Set(map.blocked.map(([x, y]) => `${x},${y}`));
+  const hazard = new Set(
+    (map.hazards ?? []).flatMap((h) => h.tiles.map(([x, y]) => `${x},${y}`)),
+  );
+  const rows: string[] = [];
+  for (let y = 0; y < map.height; y++) {
+    let row = "";
+    for (let x = 0; x < map.width; x++) {
+      const key = `${x},${y}`;
+      if (blocked.has(key)) row += ASCII_BLOCKED;
+      else if (hazard.has(key)) row += ASCII_HAZARD;
+      else if ((map.moveCost?.[key] ?? 1) > 1) row += ASCII_DIFFICULT;
+      else row += ASCII_FLOOR;
+    }
+    rows.push(row);
+  }
+  return rows.join("\n");
+}
+
+/** Move cost assigned to ASCII_DIFFICULT tiles by mapFromAscii. */
+const ASCII_DIFFICULT_COST = 2;
+
+/**
+ * Parse a terrain grid: "." floor, "#" blocked, ":" difficult (moveCost 2).
+ * Inverse of mapToAscii for maps without hazards. Hazard and unit glyphs
+ * carry data a single character can't hold, so they are rejected along with
+ * any other unknown character. Throws on ragged rows or an unknown glyph,
+ * naming its x,y position. CRLF line endings and a single trailing newline
+ * (as left by most editors) are accepted.
+ */
+export function mapFromAscii(text: string): MapState {
+  const rows = text.replace(/\r?\n$/, "").split(/\r?\n/);
+  const width = rows[0].length;
+  const blocked: Array<[number, number]> = [];
+  const moveCost: Record<string, number> = {};
+  rows.forEach((row, y) => {
+    if (row.length !== width) {
+      throw new Error(`ascii map row ${y} has width ${row.length}, expected ${width}`);
+    }
+    for (let x = 0; x < width; x++) {
+      const ch = row[x];
+      if (ch === ASCII_BLOCKED) blocked.push([x, y]);
+      else if (ch === ASCII_DIFFICULT) moveCost[`${x},${y}`] = ASCII_DIFFICULT_COST;
+      else if (ch !== ASCII_FLOOR) throw new Error(`unknown ascii map glyph "${ch}" at (${x},${y})`);
+    }
+  });
+  return {
+    width,
+    height: width === 0 ? 0 : rows.length,
+    blocked,
+    ...(Object.keys(moveCost).length > 0 && { moveCost }),
+  };
+}
+
+/**
+ * Render terrain with living units drawn on top. Dead units are omitted so
+ * the output matches what `isOccupied` reports.
+ */
+export function battleToAscii(state: BattleState): string {
+  const grid = mapToAscii(state.battleMap).split("\n").map((row) => row.split(""));
+  const units = Object.values(state.units)
+    .filter(unitAlive)
+    .sort((a, b) => a.unitId.localeCompare(b.unitId));
+  for (const unit of units) {
+    const row = grid[unit.y];
+    if (!row || unit.x < 0 || unit.x >= row.length) continue;
+    row[unit.x] = ASCII_TEAM_GLYPHS[unit.team] ?? "?";
+  }
+  return grid.map((row) => row.join("")).join("\n");
+}
@@ -0,0 +1,88 @@
+/**
+ * Grid performance baselines. Run with `npm run bench` — bench files are
+ * outside the `*.test.ts` include, so `npm run test` never picks them up.
+ */
+
+import { bench, describe } from "vitest";
+import { createTestBattle, createTestUnit, createTestRNG } from "../test-utils/fixtures";
+import { applyCommand } from "../engine/reducer";
+import type { BattleState, UnitState } from "../engine/state";
+import { hasTileLineOfEffect } from "./loe";
+import { reachableWithPrev } from "./movement";
+
+const SIZE = 64;
+
+/** Serpentine maze: a wall on every fourth column, gap alternating top/bottom. */
+function mazeBlocked(size: number): Array<[number, number]> {
+  const blocked: Array<[number, number]> = [];
+  for (let x = 2, flip = false; x < size; x += 4, flip = !flip) {
+    const gapY = flip ? 0 : size - 1;
+    for (let y = 0; y < size; y++) {
+      if (y !== gapY) blocked.push([x, y]);
+    }
+  }
+  return blocked;
+}
+
+/** Open field with a sparse pillar lattice — gives LOE something to hit. */
+function pillarBlocked(size: number): Array<[number, number]> {
+  const blocked: Array<[number, number]> = [];
+  for (let x = 3; x < size; x += 6) {
+    for (let y = 3; y < size; y += 6) blocked.push([x, y]);
+  }
+  return blocked;
+}
+
+function bigBattle(blocked: Array<[number, number]>, units: Record<string, UnitState>): BattleState {
+  return createTestBattle({
+    units,
+    battleMap: { width: SIZE, height: SIZE, blocked },
+  });
+}
+
+describe("pathfinding", () => {
+  const state = bigBattle(mazeBlocked(SIZE), {
+    runner: createTestUnit({ unitId: "runner", x: 0, y: 0, speed: 120 }),
+  });
+
+  bench("reachableWithPrev — 64×64 maze, speed 120", () => {
+    reachableWithPrev(state, "runner");
+  });
+});
+
+describe("line of effect", () => {
+  const state = bigBattle(pillarBlocked(SIZE), {
+    u: createTestUnit({ unitId: "u", x: SIZE / 2, y: SIZE / 2 }),
+  });
+  const cx = SIZE / 2;
+  const cy = SIZE / 2;
+
+  for (const radius of [6, 16, 31]) {
+    bench(`hasTileLineOfEffect — every tile within radius ${radius}`, () => {
+      for (let x = cx - radius; x <= cx + radius; x++) {
+        for (let y = cy - radius; y <= cy + radius; y++) {
+          hasTileLineOfEffect(state, cx, cy, x, y);
+        }
+      }
+    });
+  }
+});
+
+describe("reducer", () => {
+  const units: Record<string, UnitState> = {};
+  for (let i = 0; i < 40; i++) {
+    const id = `u${String(i).padStart(2, "0")}`;
+    // x is a multiple of 4, so nobody stands on a maze wall (walls are at x ≡ 2 mod 4)
+    units[id] = createTestUnit({
+      unitId: id,
+      team: i % 2 ? "enemy" : "pc",
+      x: (i % 16) * 4,
+      y: Math.floor(i / 16) * 2 + 1,
+    });
+  }
+  const state = bigBattle(mazeBlocked(SIZE), units);
+
+  bench("applyCommand end_turn — 40 units, 64×64 maze (deepClone-dominated)", () => {
+    applyCommand(state, { type: "end_turn", actor: state.turnOrder[0] }, createTestRNG());
+  });
+});
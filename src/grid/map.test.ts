@@ -0,0 +1,145 @@
+import { describe, it, expect } from "vitest";
+import { createTestBattle, createTestMap, createTestUnit } from "../test-utils/fixtures";
+import { mapTiles, neighbors, resizeBattleMap, resizeMap, stitchMaps } from "./map";
+import { mapFromAscii, mapToAscii } from "./ascii";
+import { reachableTiles } from "./movement";
+
+describe("mapTiles", () => {
+  it("visits every tile exactly once in row-major order", () => {
+    const tiles = [...mapTiles(createTestMap({ width: 3, height: 2 }))];
+    expect(tiles).toEqual([
+      [0, 0], [1, 0], [2, 0],
+      [0, 1], [1, 1], [2, 1],
+    ]);
+    expect(new Set(tiles.map(([x, y]) => `${x},${y}`)).size).toBe(6);
+  });
+
+  it("yields nothing for an empty map", () => {
+    expect([...mapTiles(createTestMap({ width: 0, height: 4 }))]).toEqual([]);
+  });
+});
+
+describe("neighbors", () => {
+  const map = createTestMap({ width: 4, height: 3 });
+  const sorted = (tiles: Array<[number, number]>) =>
+    tiles.map(([x, y]) => `${x},${y}`).sort();
+
+  it("corner tile yields only in-bounds neighbors", () => {
+    expect(sorted([...neighbors(map, 0, 0)])).toEqual(["0,1", "1,0", "1,1"]);
+    expect(sorted([...neighbors(map, 0, 0, false)])).toEqual(["0,1", "1,0"]);
+  });
+
+  it("edge tile drops the off-map column", () => {
+    expect(sorted([...neighbors(map, 3, 1)])).toEqual(["2,0", "2,1", "2,2", "3,0", "3,2"]);
+    expect(sorted([...neighbors(map, 3, 1, false)])).toEqual(["2,1", "3,0", "3,2"]);
+  });
+
+  it("interior tile yields all eight, orthogonal first", () => {
+    const all = [...neighbors(map, 1, 1)];
+    expect(all).toHaveLength(8);
+    expect(all.slice(0, 4)).toEqual([[1, 2], [1, 0], [2, 1], [0, 1]]);
+    expect([...neighbors(map, 1, 1, false)]).toEqual(all.slice(0, 4));
+  });
+});
+
+describe("stitchMaps", () => {
+  const room = mapFromAscii(["...", "..:", "..."].join("\n"));
+  const corridor = mapFromAscii("...");
+
+  it("joins two rooms with a corridor and fills the gaps with rock", () => {
+    const map = stitchMaps([
+      { map: room, x: 0, y: 0 },
+      { map: corridor, x: 3, y: 1 },
+      { map: room, x: 6, y: 0 },
+    ]);
+    expect(mapToAscii(map)).toBe(["...###...", "..:.....:", "...###..."].join("\n"));
+    expect(map.moveCost).toEqual({ "2,1": 2, "8,1": 2 });
+
+    const state = createTestBattle({
+      battleMap: map,
+      units: { runner: createTestUnit({ unitId: "runner", x: 0, y: 0, speed: 20 }) },
+    });
+    expect(reachableTiles(state, "runner").has("8,2")).toBe(true);
+  });
+
+  it("translates hazard zones by the placement offset", () => {
+    const pit = createTestMap({
+      width: 2,
+      height: 2,
+      hazards: [{ id: "acid", damageType: "acid", damagePerTurn: 3, dc: 15, saveType: "Reflex", tiles: [[1, 1]] }],
+    });
+    const map = stitchMaps([{ map: pit, x: 4, y: 5 }]);
+    expect(map.hazards?.[0].tiles).toEqual([[5, 6]]);
+  });
+
+  it("rejects overlapping placements", () => {
+    expect(() => stitchMaps([
+      { map: room, x: 0, y: 0 },
+      { map: corridor, x: 2, y: 1 },
+    ])).toThrow("stitched maps 0 and 1 overlap at (2,1)");
+  });
+});
+
+describe("resizeMap", () => {
+  const map = mapFromAscii(["#..", ".:.", "..#"].join("\n"));
+
+  it("grows with floor or blocked fill and keeps existing tiles", () => {
+    expect(mapToAscii(resizeMap(map, 4, 4))).toBe(["#...", ".:..", "..#.", "...."].join("\n"));
+    expect(mapToAscii(resizeMap(map, 4, 4, "blocked"))).toBe(["#..#", ".:.#", "..##", "####"].join("\n"));
+  });
+
+  it("shrinks by dropping tiles and layers past the new edge", () => {
+    const hazardous = {
+      ...map,
+      elevation: { "0,1": 1, "2,2": 3 },
+      hazards: [
+        { id: "near", damageType: "fire", damagePerTurn: 1, dc: 15, saveType: "Reflex", tiles: [[1, 0], [2, 0]] as Array<[number, number]> },
+        { id: "far", damageType: "fire", damagePerTurn: 1, dc: 15, saveType: "Reflex", tiles: [[2, 2]] as Array<[number, number]> },
+      ],
+    };
+    const small = resizeMap(hazardous, 2, 2);
+    expect(mapToAscii(small)).toBe(["#^", ".:"].join("\n"));
+    expect(small.blocked).toEqual([[0, 0]]);
+    expect(small.elevation).toEqual({ "0,1": 1 });
+    expect(small.hazards?.map((h) => h.id)).toEqual(["near"]);
+    expect(small.hazards?.[0].tiles).toEqual([[1, 0]]);
+  });
+
+  it("grow then shrink back is lossless", () => {
+    expect(mapToAscii(resizeMap(resizeMap(map, 5, 5), 3, 3))).toBe(mapToAscii(map));
+  });
+});
+
+describe("resizeBattleMap", () => {
+  const battle = () => createTestBattle({
+    battleMap: createTestMap({ width: 6, height: 6 }),
+    units: {
+      hero: createTestUnit({ unitId: "hero", x: 1, y: 1 }),
+      scout: createTestUnit({ unitId: "scout", x: 4, y: 2 }),
+    },
+  });
+
+  it("resizes the map when every living unit still fits", () => {
+    const start = battle();
+    const resized = resizeBattleMap(start, 5, 3);
+    expect(resized.battleMap.width).toBe(5);
+    expect(resized.units).toBe(start.units);
+    expect(start.battleMap.width).toBe(6);
+  });
+
+  it("throws, naming the unit, when a shrink strands it", () => {
+    expect(() => resizeBattleMap(battle(), 4, 4)).toThrow("unit scout at (4, 2) is outside the resized 4x4 map");
+  });
+
+  it("ignores dead units past the new edge", () => {
+    const start = battle();
+    start.units.scout.hp = 0;
+    expect(resizeBattleMap(start, 4, 4).battleMap.width).toBe(4);
+  });
+
+  it("throws when blocked fill would wall in a unit", () => {
+    const start = battle();
+    start.battleMap = createTestMap({ width: 4, height: 4 });
+    expect(() => resizeBattleMap(start, 6, 6, "blocked")).toThrow(/unit scout .* blocked fill tile/);
+  });
+});
@@ -2,7 +2,7 @@
  * Grid map helpers.
  */
 
-import { BattleState, unitAlive } from "../engine/state";
+import { BattleState, HazardZone, MapState, unitAlive } from "../engine/state";
 
 export function inBounds(state: BattleState, x: number, y: number): boolean {
   return x >= 0 && x < state.battleMap.width && y >= 0 && y < state.battleMap.height;
@@ -22,3 +22,193 @@ export function isOccupied(state: BattleState, x: number, y: number): boolean {
 export function tilesFromFeet(feet: number): number {
   return Math.max(1, Math.floor((feet + 4) / 5));
 }
+
+/**
+ * Every in-bounds tile coordinate, row-major (y outer, x inner) — the same
+ * order the renderers draw in. Use this instead of hand-written nested loops
+ * over width/height.
+ */
+export function* mapTiles(map: MapState): Generator<[number, number]> {
+  for (let y = 0; y < map.height; y++) {
+    for (let x = 0; x < map.width; x++) {
+      yield [x, y];
+    }
+  }
+}
+
+/** 4-connected step deltas. */
+export const ORTHOGONAL_DIRS: ReadonlyArray<readonly [number, number]> = [
+  [0, 1], [0, -1], [1, 0], [-1, 0],
+];
+
+/** 8-connected step deltas: orthogonal then diagonal. Dijkstra relaxes in
+ *  this order, so changing it changes tie-breaks in path reconstruction. */
+export const GRID_DIRS: ReadonlyArray<readonly [number, number]> = [
+  ...ORTHOGONAL_DIRS,
+  [1, 1], [1, -1], [-1, 1], [-1, -1],
+];
+
+/**
+ * In-bounds tiles adjacent to `(x, y)`, in `GRID_DIRS` order. Pass
+ * `diagonal: false` for 4-connected adjacency. Only bounds are checked —
+ * blocked/occupied filtering is the caller's job since movement, reach, and
+ * area effects each treat those differently.
+ */
+export function* neighbors(
+  map: MapState,
+  x: number,
+  y: number,
+  diagonal = true,
+): Generator<[number, number]> {
+  for (const [dx, dy] of diagonal ? GRID_DIRS : ORTHOGONAL_DIRS) {
+    const nx = x + dx;
+    const ny = y + dy;
+    if (nx < 0 || ny < 0 || nx >= map.width || ny >= map.height) continue;
+    yield [nx, ny];
+  }
+}
+
+/** A sub-map and the offset of its top-left tile within a stitched map. */
+export interface MapPlacement {
+  map: MapState;
+  x: number;
+  y: number;
+}
+
+function shiftTileRecord(
+  rec: Record<string, number> | undefined,
+  dx: number,
+  dy: number,
+  out: Record<string, number>,
+): void {
+  for (const [key, value] of Object.entries(rec ?? {})) {
+    const [x, y] = key.split(",").map(Number);
+    out[`${x + dx},${y + dy}`] = value;
+  }
+}
+
+/**
+ * Combine sub-maps (rooms, corridors, prefabs) into one map. Every per-tile
+ * layer and hazard zone is translated by its placement offset. Tiles no
+ * placement covers become blocked — solid rock between rooms. Throws when
+ * two placements overlap, an offset is negative, or hazard ids collide.
+ */
+export function stitchMaps(parts: ReadonlyArray<MapPlacement>): MapState {
+  const width = Math.max(0, ...parts.map((p) => p.x + p.map.width));
+  const height = Math.max(0, ...parts.map((p) => p.y + p.map.height));
+  const owner = new Map<string, number>();
+  const blocked: Array<[number, number]> = [];
+  const moveCost: Record<string, number> = {};
+  const coverGrade: Record<string, number> = {};
+  const elevation: Record<string, number> = {};
+  const hazards: HazardZone[] = [];
+
+  parts.forEach(({ map, x: dx, y: dy }, i) => {
+    if (dx < 0 || dy < 0) throw new Error(`stitched map ${i} has negative offset (${dx},${dy})`);
+    for (const [x, y] of mapTiles(map)) {
+      const key = `${x + dx},${y + dy}`;
+      const other = owner.get(key);
+      if (other !== undefined) throw new Error(`stitched maps ${other} and ${i} overlap at (${key})`);
+      owner.set(key, i);
+    }
+    for (const [x, y] of map.blocked) blocked.push([x + dx, y + dy]);
+    shiftTileRecord(map.moveCost, dx, dy, moveCost);
+    shiftTileRecord(map.coverGrade, dx, dy, coverGrade);
+    shiftTileRecord(map.elevation, dx, dy, elevation);
+    for (const zone of map.hazards ?? []) {
+      if (hazards.some((h) => h.id === zone.id)) throw new Error(`duplicate hazard id ${zone.id} in stitched map ${i}`);
+      hazards.push({ ...zone, tiles: zone.tiles.map(([x, y]) => [x + dx, y + dy]) });
+    }
+  });
+
+  for (const [x, y] of mapTiles({ width, height, blocked: [] })) {
+    if (!owner.has(`${x},${y}`)) blocked.push([x, y]);
+  }
+
+  return {
+    width,
+    height,
+    blocked,
+    ...(Object.keys(moveCost).length > 0 && { moveCost }),
+    ...(Object.keys(coverGrade).length > 0 && { coverGrade }),
+    ...(Object.keys(elevation).length > 0 && { elevation }),
+    ...(hazards.length > 0 && { hazards }),
+  };
+}
+
+function clipTileRecord(
+  rec: Record<string, number>,
+  width: number,
+  height: number,
+): Record<string, number> {
+  const out: Record<string, number> = {};
+  for (const [key, value] of Object.entries(rec)) {
+    const [x, y] = key.split(",").map(Number);
+    if (x < width && y < height) out[key] = value;
+  }
+  return out;
+}
+
+/**
+ * Grow or shrink a map with the top-left corner fixed. Blocked tiles, per-tile
+ * layers, and hazard tiles past the new edge are dropped, along with hazard
+ * zones left with no tiles. New tiles are floor, or blocked with
+ * `fill: "blocked"`. Units are not part of MapState — use resizeBattleMap to
+ * resize a live battle.
+ */
+export function resizeMap(
+  map: MapState,
+  width: number,
+  height: number,
+  fill: "floor" | "blocked" = "floor",
+): MapState {
+  if (!Number.isInteger(width) || !Number.isInteger(height) || width < 0 || height < 0) {
+    throw new Error(`invalid map size ${width}x${height}`);
+  }
+  const inside = ([x, y]: [number, number]) => x < width && y < height;
+  const blocked = map.blocked.filter(inside);
+  if (fill === "blocked") {
+    for (const [x, y] of mapTiles({ width, height, blocked: [] })) {
+      if (x >= map.width || y >= map.height) blocked.push([x, y]);
+    }
+  }
+  const hazards = map.hazards
+    ?.map((zone) => ({ ...zone, tiles: zone.tiles.filter(inside) }))
+    .filter((zone) => zone.tiles.length > 0);
+
+  return {
+    width,
+    height,
+    blocked,
+    ...(map.moveCost && { moveCost: clipTileRecord(map.moveCost, width, height) }),
+    ...(map.coverGrade && { coverGrade: clipTileRecord(map.coverGrade, width, height) }),
+    ...(map.elevation && { elevation: clipTileRecord(map.elevation, width, height) }),
+    ...(hazards && { hazards }),
+  };
+}
+
+/**
+ * resizeMap for a live battle. Throws, naming the unit, if a living unit
+ * would end up off the map or on a tile the resize turns into wall; move
+ * or remove it first. Dead units are left where they are. Returns a new
+ * state — the input is not mutated.
+ */
+export function resizeBattleMap(
+  state: BattleState,
+  width: number,
+  height: number,
+  fill: "floor" | "blocked" = "floor",
+): BattleState {
+  const battleMap = resizeMap(state.battleMap, width, height, fill);
+  const resized = { ...state, battleMap };
+  for (const unit of Object.values(state.units)) {
+    if (!unitAlive(unit)) continue;
+    if (!inBounds(resized, unit.x, unit.y)) {
+      throw new Error(`unit ${unit.unitId} at (${unit.x}, ${unit.y}) is outside the resized ${width}x${height} map`);
+    }
+    if (isBlocked(resized, unit.x, unit.y) && !isBlocked(state, unit.x, unit.y)) {
+      throw new Error(`unit ${unit.unitId} at (${unit.x}, ${unit.y}) would stand on a blocked fill tile`);
+    }
+  }
+  return resized;
+}
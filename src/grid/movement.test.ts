@@ -7,6 +7,8 @@ import {
   stepToward,
   canStepTo,
   chebyshevDistance,
+  gridDistance,
+  pf2eDiagonalDistance,
 } from "./movement";
 import type { BattleState } from "../engine/state";
 
@@ -67,6 +69,36 @@ describe("chebyshevDistance", () => {
   });
 });
 
+describe("gridDistance", () => {
+  it("compares metrics on a pure diagonal", () => {
+    // (0,0) → (4,4): four diagonal steps
+    expect(gridDistance(0, 0, 4, 4, "chebyshev")).toBe(4);
+    expect(gridDistance(0, 0, 4, 4, "manhattan")).toBe(8);
+    expect(gridDistance(0, 0, 4, 4, "euclidean")).toBeCloseTo(5.657, 3);
+    expect(gridDistance(0, 0, 4, 4, "pf2e")).toBe(6); // 1+2+1+2
+  });
+
+  it("pf2e: odd diagonal count rounds the extra cost down", () => {
+    expect(pf2eDiagonalDistance(0, 0, 1, 1)).toBe(1);
+    expect(pf2eDiagonalDistance(0, 0, 2, 2)).toBe(3);
+    expect(pf2eDiagonalDistance(0, 0, 3, 3)).toBe(4);
+    // 2 diagonals + 3 straight
+    expect(pf2eDiagonalDistance(0, 0, 5, 2)).toBe(6);
+  });
+
+  it("pf2e distance matches dijkstra cost on an open map", () => {
+    const battle = createTestBattle({
+      units: { u: createTestUnit({ unitId: "u", x: 0, y: 0, speed: 20 }) },
+      battleMap: { width: 10, height: 10, blocked: [] },
+    });
+    const { dist } = reachableWithPrev(battle, "u");
+    for (const [key, cost] of dist) {
+      const [x, y] = key.split(",").map(Number);
+      expect(pf2eDiagonalDistance(0, 0, x, y)).toBe(cost);
+    }
+  });
+});
+
 describe("8-connected reachability", () => {
   it("includes diagonal tiles on an open map", () => {
     const battle = createTestBattle({
@@ -5,7 +5,7 @@
  */
 
 import { BattleState, UnitState } from "../engine/state";
-import { inBounds, isBlocked, isOccupied } from "./map";
+import { inBounds, isBlocked, isOccupied, neighbors } from "./map";
 
 export function manhattanDistance(
   ax: number,
@@ -26,6 +26,49 @@ export function chebyshevDistance(
   return Math.max(Math.abs(ax - bx), Math.abs(ay - by));
 }
 
+/**
+ * PF2e movement distance in tiles: diagonals alternate 1 and 2 tiles
+ * (5 ft / 10 ft), starting with 1. Matches the cost `dijkstra` charges on an
+ * open map with no difficult terrain.
+ */
+export function pf2eDiagonalDistance(
+  ax: number,
+  ay: number,
+  bx: number,
+  by: number,
+): number {
+  const dx = Math.abs(ax - bx);
+  const dy = Math.abs(ay - by);
+  const diagonals = Math.min(dx, dy);
+  return Math.max(dx, dy) + Math.floor(diagonals / 2);
+}
+
+export type DistanceMetric = "chebyshev" | "manhattan" | "euclidean" | "pf2e";
+
+/**
+ * Grid distance in tiles under the given metric. Reach and range checks use
+ * "chebyshev"; "pf2e" is the alternating-diagonal movement measure.
+ * "euclidean" is not rounded.
+ */
+export function gridDistance(
+  ax: number,
+  ay: number,
+  bx: number,
+  by: number,
+  metric: DistanceMetric,
+): number {
+  switch (metric) {
+    case "chebyshev":
+      return chebyshevDistance(ax, ay, bx, by);
+    case "manhattan":
+      return manhattanDistance(ax, ay, bx, by);
+    case "euclidean":
+      return Math.hypot(ax - bx, ay - by);
+    case "pf2e":
+      return pf2eDiagonalDistance(ax, ay, bx, by);
+  }
+}
+
 /**
  * Can `unit` step to adjacent tile `(x, y)` in one move?
  * Allows 8-connected adjacency (Chebyshev distance 1).
@@ -53,12 +96,6 @@ export function canStepTo(
   return true;
 }
 
-// 8-connected directions: orthogonal then diagonal
-const DIRS: ReadonlyArray<readonly [number, number]> = [
-  [0, 1], [0, -1], [1, 0], [-1, 0],   // orthogonal
-  [1, 1], [1, -1], [-1, 1], [-1, -1],  // diagonal
-];
-
 interface DijkstraResult {
   /** Minimum cost to reach each visited tile, keyed by "x,y". */
   dist: Map<string, number>;
@@ -92,7 +129,6 @@ function dijkstra(
   movingUnitId: string,
   maxCost: number,
 ): DijkstraResult {
-  const { width, height } = state.battleMap;
   const blockedSet = new Set(state.battleMap.blocked.map(([bx, by]) => `${bx},${by}`));
   const occupiedSet = new Set(
     Object.values(state.units)
@@ -122,10 +158,9 @@ function dijkstra(
       const stateKey = `${x},${y},${parity}`;
       if (stateDist.get(stateKey) !== c) continue; // stale entry
 
-      for (const [dx, dy] of DIRS) {
-        const nx = x + dx;
-        const ny = y + dy;
-        if (nx < 0 || ny < 0 || nx >= width || ny >= height) continue;
+      for (const [nx, ny] of neighbors(state.battleMap, x, y)) {
+        const dx = nx - x;
+        const dy = ny - y;
         const nkey = `${nx},${ny}`;
         if (blockedSet.has(nkey) || occupiedSet.has(nkey)) continue;
 
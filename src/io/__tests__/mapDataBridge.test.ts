@@ -11,6 +11,7 @@ import {
   extractObjectives,
   extractMapProperties,
   buildScenarioFromTiledMap,
+  applyTiledTerrain,
 } from "../mapDataBridge";
 import { validateScenario, battleStateFromScenario } from "../scenarioLoader";
 import type { ResolvedTiledMap, TiledTileset, TiledLayer } from "../tiledTypes";
@@ -500,3 +501,24 @@ describe("buildScenarioFromTiledMap", () => {
     expect(() => buildScenarioFromTiledMap(map)).toThrow(/battleId/);
   });
 });
+
+// ---------------------------------------------------------------------------
+// applyTiledTerrain
+// ---------------------------------------------------------------------------
+
+describe("applyTiledTerrain", () => {
+  const scenario = {
+    battle_id: "hybrid",
+    seed: 1,
+    map: { width: 10, height: 8, blocked: [[4, 3]] },
+    units: [{ id: "hero", team: "pc", hp: 10, position: [0, 0], initiative: 10, attack_mod: 4, ac: 14, damage: "1d6" }],
+    commands: [],
+  };
+
+  it("replaces the JSON map block with the Tiled terrain", () => {
+    const merged = applyTiledTerrain(scenario, makeMap());
+    expect(merged["map"]).toEqual({ width: 3, height: 3, blocked: [[1, 1]] });
+    expect(merged["units"]).toBe(scenario.units);
+    expect(() => validateScenario(merged)).not.toThrow();
+  });
+});
@@ -0,0 +1,65 @@
+/**
+ * Tests for saveMigration.ts — loading BattleState saves written by older builds.
+ *
+ * fixtures/legacy_battle_v1.json is a save from before reach, attacksThisTurn,
+ * abilitiesRemaining, and tempHpOwnerEffectId existed. Keep it unchanged; add
+ * a new fixture when a later field needs coverage.
+ */
+
+import { describe, it, expect } from "vitest";
+import { readFileSync } from "fs";
+import { restoreBattleState } from "../saveMigration";
+import { applyCommand } from "../../engine/reducer";
+import { createTestBattle, createTestRNG } from "../../test-utils/fixtures";
+
+const legacy = (): Record<string, unknown> =>
+  JSON.parse(readFileSync("src/io/__tests__/fixtures/legacy_battle_v1.json", "utf-8"));
+
+describe("restoreBattleState", () => {
+  it("fills fields added after the legacy snapshot with scenario-loader defaults", () => {
+    const battle = restoreBattleState(legacy())!;
+    expect(battle).not.toBeNull();
+    const hero = battle.units["hero"];
+    expect(hero.reach).toBe(1);
+    expect(hero.attacksThisTurn).toBe(0);
+    expect(hero.abilitiesRemaining).toEqual({});
+    expect(hero.tempHpOwnerEffectId).toBeNull();
+  });
+
+  it("keeps every field the save did carry", () => {
+    const battle = restoreBattleState(legacy())!;
+    expect(battle.roundNumber).toBe(2);
+    expect(battle.turnIndex).toBe(1);
+    expect(battle.eventSequence).toBe(17);
+    expect(battle.units["hero"].hp).toBe(30);
+    expect(battle.units["hero"].actionsRemaining).toBe(0);
+    expect(battle.units["hero"].reactionAvailable).toBe(false);
+    expect(battle.units["hero"].conditions).toEqual({ frightened: 1 });
+    expect(battle.battleMap.blocked).toEqual([[4, 3], [5, 3]]);
+  });
+
+  it("restored state is playable through the reducer", () => {
+    const battle = restoreBattleState(legacy())!;
+    const [next] = applyCommand(battle, { type: "end_turn", actor: "orc" }, createTestRNG());
+    expect(next.roundNumber).toBe(3);
+    expect(next.units["hero"].actionsRemaining).toBe(3);
+  });
+
+  it("does not share default records between units", () => {
+    const battle = restoreBattleState(legacy())!;
+    expect(battle.units["hero"].abilitiesRemaining).not.toBe(battle.units["orc"].abilitiesRemaining);
+  });
+
+  it("round-trips a current-format state unchanged", () => {
+    const current = createTestBattle();
+    expect(restoreBattleState(JSON.parse(JSON.stringify(current)))).toEqual(current);
+  });
+
+  it("rejects a save missing a field with no neutral default", () => {
+    const data = legacy();
+    delete (data["units"] as Record<string, Record<string, unknown>>)["orc"]["hp"];
+    expect(restoreBattleState(data)).toBeNull();
+    expect(restoreBattleState({ ...legacy(), battleMap: undefined })).toBeNull();
+    expect(restoreBattleState("not a save")).toBeNull();
+  });
+});
@@ -19,7 +19,7 @@ import { hasLineOfSight } from "../grid/los";
 import { hasTileLineOfEffect } from "../grid/loe";
 import { radiusPoints } from "../grid/areas";
 import { tilesFromFeet } from "../grid/map";
-import { stepToward } from "../grid/movement";
+import { chebyshevDistance, stepToward } from "../grid/movement";
 import { thrownRange } from "../engine/traits";
 
 // ---------------------------------------------------------------------------
@@ -227,7 +227,7 @@ function enemyCandidates(state: BattleState, actorId: string): EnemyCandidate[]
     .filter((u) => unitAlive(u) && u.team !== actor.team)
     .map((u) => ({
       unitId: u.unitId,
-      dist: Math.max(Math.abs(u.x - actor.x), Math.abs(u.y - actor.y)),
+      dist: chebyshevDistance(u.x, u.y, actor.x, actor.y),
       hasLos: hasLineOfSight(state, actor, u),
     }))
     .sort((a, b) => a.dist - b.dist || a.unitId.localeCompare(b.unitId));
@@ -0,0 +1,75 @@
+import { describe, it, expect } from "vitest";
+import { createAoOUnit, createTestBattle, createTestUnit, createTestRNG } from "../test-utils/fixtures";
+import { buildOrchestratorConfig } from "./battleOrchestrator";
+import { simulateBattle } from "./battleSimulation";
+import type { BattleState } from "../engine/state";
+
+const config = buildOrchestratorConfig({ enemy_policy: { enabled: true, teams: ["enemy"] } });
+
+/** A heavily armed hero against a single weak goblin, a few tiles apart. */
+function lopsidedBattle(): BattleState {
+  return createTestBattle({
+    units: {
+      hero: createTestUnit({ unitId: "hero", team: "pc", x: 1, y: 1, hp: 40, maxHp: 40, attackMod: 15, damage: "2d8+6", initiative: 20 }),
+      goblin: createTestUnit({ unitId: "goblin", team: "enemy", x: 6, y: 5, hp: 8, maxHp: 8, ac: 12, damage: "1d4", initiative: 10 }),
+    },
+    turnOrder: ["hero", "goblin"],
+  });
+}
+
+describe("simulateBattle", () => {
+  it("resolves a clearly winnable fight to victory within the round cap", () => {
+    const start = lopsidedBattle();
+    const before = JSON.stringify(start);
+    const result = simulateBattle(start, config, createTestRNG(), { maxRounds: 10 });
+
+    expect(result.outcome).toBe("victory");
+    expect(result.rounds).toBeLessThanOrEqual(10);
+    expect(result.state.units.goblin.hp).toBe(0);
+    expect(JSON.stringify(start)).toBe(before);
+  });
+
+  it("is deterministic for a given seed", () => {
+    const a = simulateBattle(lopsidedBattle(), config, createTestRNG(7));
+    const b = simulateBattle(lopsidedBattle(), config, createTestRNG(7));
+    expect(b).toEqual(a);
+  });
+
+  describe("with both sides passing", () => {
+    const idle = buildOrchestratorConfig({ enemy_policy: { enabled: false } });
+    const pass = (state: BattleState) => ({ type: "end_turn", actor: state.turnOrder[state.turnIndex] });
+
+    it("stops at the round cap with no outcome", () => {
+      const result = simulateBattle(lopsidedBattle(), idle, createTestRNG(), { maxRounds: 3, partyPolicy: pass });
+      expect(result.outcome).toBeNull();
+      expect(result.rounds).toBe(3);
+      expect(result.commands).toBe(6);
+    });
+
+    it("stops early when the until predicate holds", () => {
+      const result = simulateBattle(lopsidedBattle(), idle, createTestRNG(), {
+        partyPolicy: pass,
+        until: (state) => state.roundNumber >= 2,
+      });
+      expect(result.outcome).toBeNull();
+      expect(result.rounds).toBe(2);
+    });
+  });
+
+  it("resolves reactions the way the store does", () => {
+    const start = createTestBattle({
+      units: {
+        hero: createTestUnit({ unitId: "hero", team: "pc", x: 1, y: 0, hp: 40, maxHp: 40, initiative: 20 }),
+        ogre: createAoOUnit({ unitId: "ogre", x: 1, y: 1, initiative: 10 }),
+      },
+      turnOrder: ["hero", "ogre"],
+    });
+    // Moving while in the ogre's reach provokes an Attack of Opportunity
+    const result = simulateBattle(start, config, createTestRNG(), {
+      partyPolicy: () => ({ type: "move", actor: "hero", x: 2, y: 0 }),
+      until: (state) => state.units.hero.x === 2,
+    });
+    expect(result.commands).toBe(2);
+    expect(result.state.units.ogre.reactionAvailable).toBe(false);
+  });
+});
@@ -0,0 +1,177 @@
+/**
+ * Headless battle simulation — runs a battle to completion with no UI.
+ *
+ * Enemy turns use getAiCommand with the scenario's enemy policy, and
+ * player-team turns use a caller-supplied party policy (default:
+ * strike_nearest). Every command goes through applyCommand. Reactions are
+ * resolved after each command the way the store resolves them: triggers
+ * from detectCommandReactions, cascades (reaction_strike → shield_block)
+ * first, AI reactors always accept. Player-team reactors accept unless the
+ * `acceptReaction` option says otherwise — the store would prompt instead.
+ *
+ * Intended for tests and balance tooling — long fights can be asserted on
+ * without hand-writing every end_turn.
+ */
+
+import { applyCommand, ReductionError } from "../engine/reducer";
+import { DeterministicRNG } from "../engine/rng";
+import { BattleState, activeUnitId } from "../engine/state";
+import { RawCommand } from "../engine/commands";
+import { type ReactionTrigger, detectCommandReactions, reactionCommand } from "../engine/reactions";
+import { type ContentContext } from "./contentPackLoader";
+import {
+  type BattleOutcome,
+  type EnemyPolicy,
+  type OrchestratorConfig,
+  checkBattleEnd,
+  getAiCommand,
+  isAiUnit,
+  materializeRawCommand,
+} from "./battleOrchestrator";
+
+/** Picks the next command for a player-team unit. */
+export type PartyPolicy = (state: BattleState) => RawCommand;
+
+export interface SimulationOptions {
+  /** Stop once this many rounds have been played. Default 20. */
+  maxRounds?: number;
+  /** Player-team policy. Default: strike_nearest over config.playerTeams. */
+  partyPolicy?: PartyPolicy;
+  /** Whether a player-team reactor takes a reaction. Default: always. AI reactors always do. */
+  acceptReaction?: (trigger: ReactionTrigger, state: BattleState) => boolean;
+  /** Extra stop condition, checked after every command alongside checkBattleEnd. */
+  until?: (state: BattleState) => boolean;
+  /** Needed only when a policy issues content-entry commands. */
+  contentContext?: ContentContext | null;
+}
+
+export interface SimulationResult {
+  /** null when the round cap (or `until`) stopped the run before a winner. */
+  outcome: BattleOutcome | null;
+  /** Rounds played — never more than maxRounds. */
+  rounds: number;
+  /** Commands applied successfully, reactions and forced end_turns included. */
+  commands: number;
+  state: BattleState;
+}
+
+const DEFAULT_MAX_ROUNDS = 20;
+/** Safety cap per turn — a policy that never ends its turn would otherwise spin. */
+const MAX_COMMANDS_PER_TURN = 50;
+
+/** strike_nearest for the player teams — the same decision logic enemies use. */
+export function defaultPartyPolicy(config: OrchestratorConfig): PartyPolicy {
+  const policy: EnemyPolicy = {
+    enabled: true,
+    teams: config.playerTeams,
+    action: "strike_nearest",
+    contentEntryId: null,
+    dc: null,
+    autoEndTurn: true,
+  };
+  return (state) => getAiCommand(state, policy) as unknown as RawCommand;
+}
+
+/**
+ * Run `state` forward until the battle ends, `until` holds, or `maxRounds`
+ * rounds have been played. Rejected commands restore the RNG position (same
+ * rule as battleStore.dispatchCommand); a rejected turn command forces an
+ * end_turn for the active unit. The input state is not mutated.
+ */
+export function simulateBattle(
+  state: BattleState,
+  config: OrchestratorConfig,
+  rng: DeterministicRNG,
+  options: SimulationOptions = {},
+): SimulationResult {
+  const maxRounds = options.maxRounds ?? DEFAULT_MAX_ROUNDS;
+  const partyPolicy = options.partyPolicy ?? defaultPartyPolicy(config);
+  const acceptReaction = options.acceptReaction ?? (() => true);
+  const contentContext = options.contentContext ?? null;
+
+  let current = state;
+  let commands = 0;
+  let turnCommands = 0;
+  let reactionQueue: ReactionTrigger[] = [];
+
+  /** Apply one command; on success queue the reactions it provoked, cascades first. */
+  const apply = (cmd: RawCommand): boolean => {
+    const capturedCount = rng.callCount;
+    try {
+      const [next, events] = applyCommand(current, cmd, rng);
+      if (next.turnIndex !== current.turnIndex || next.roundNumber !== current.roundNumber) {
+        turnCommands = 0;
+      }
+      current = next;
+      commands++;
+      if (!checkBattleEnd(current, config).ended) {
+        const triggers = detectCommandReactions(current, cmd as Record<string, unknown>, events);
+        reactionQueue = [...triggers, ...reactionQueue];
+      }
+      return true;
+    } catch (err) {
+      if (!(err instanceof ReductionError)) throw err;
+      rng = new DeterministicRNG(rng.seed, capturedCount);
+      return false;
+    }
+  };
+
+  /** Drain the reaction queue. A rejected reaction (e.g. provoker already dead) is skipped. */
+  const resolveReactions = () => {
+    while (reactionQueue.length > 0) {
+      if (checkBattleEnd(current, config).ended) {
+        reactionQueue = [];
+        return;
+      }
+      const [trigger, ...rest] = reactionQueue;
+      reactionQueue = rest;
+      const reactor = current.units[trigger.reactorId];
+      const accept = !!reactor &&
+        (!config.playerTeams.includes(reactor.team) || acceptReaction(trigger, current));
+      const cmd = accept ? reactionCommand(trigger) : null;
+      if (cmd) apply(cmd);
+    }
+  };
+
+  const stop = (outcome: BattleOutcome | null): SimulationResult => ({
+    outcome,
+    rounds: Math.min(current.roundNumber, maxRounds),
+    commands,
+    state: current,
+  });
+
+  while (current.roundNumber <= maxRounds) {
+    const end = checkBattleEnd(current, config);
+    if (end.ended) return stop(end.outcome);
+    if (options.until?.(current)) break;
+
+    const actor = activeUnitId(current);
+    let cmd: RawCommand;
+    if (turnCommands++ >= MAX_COMMANDS_PER_TURN) {
+      cmd = { type: "end_turn", actor };
+    } else if (!isAiUnit(current, config)) {
+      cmd = partyPolicy(current);
+    } else if (!config.enemyPolicy.enabled) {
+      // Same safety net as the store: a disabled policy just passes the turn
+      cmd = { type: "end_turn", actor };
+    } else {
+      let raw = getAiCommand(current, config.enemyPolicy, contentContext);
+      if (contentContext && raw["content_entry_id"]) {
+        try {
+          raw = materializeRawCommand(raw, contentContext);
+        } catch {
+          raw = { type: "end_turn", actor };
+        }
+      }
+      cmd = raw as unknown as RawCommand;
+    }
+
+    if (!apply(cmd) && !apply({ type: "end_turn", actor })) {
+      throw new Error(`simulation stuck: end_turn rejected for ${actor}`);
+    }
+    resolveReactions();
+  }
+
+  const end = checkBattleEnd(current, config);
+  return stop(end.ended ? end.outcome : null);
+}
@@ -0,0 +1,93 @@
+import { describe, it, expect } from "vitest";
+import { createCombatBattle, createTestRNG } from "../test-utils/fixtures";
+import { applyCommand } from "../engine/reducer";
+import { combatStatsFromEvents } from "./combatStats";
+
+function ev(type: string, payload: Record<string, unknown>): Record<string, unknown> {
+  return { type, payload };
+}
+
+describe("combatStatsFromEvents", () => {
+  it("counts hits, misses, crits, damage, and kills over a scripted fight", () => {
+    const stats = combatStatsFromEvents([
+      ev("strike", { actor: "fighter", target: "orc", degree: "success", damage: { total: 7 }, target_hp: 13 }),
+      ev("strike", { actor: "fighter", target: "orc", degree: "failure", damage: null, target_hp: 13 }),
+      ev("reaction_strike", { actor: "orc", target: "fighter", degree: "critical_failure", damage: null, target_hp: 20 }),
+      ev("strike", { actor: "fighter", target: "orc", degree: "critical_success", damage: { total: 16 }, target_hp: 0 }),
+      ev("hazard_tick", { target: "fighter", hazard_id: "fire", damage: { applied_total: 3 }, target_hp: 17 }),
+      ev("move", { actor: "fighter", from: [0, 0], to: [1, 0] }),
+    ]);
+
+    expect(stats.fighter).toEqual({ damageDealt: 23, damageTaken: 3, hits: 2, misses: 1, crits: 1, kills: 1 });
+    expect(stats.orc).toEqual({ damageDealt: 0, damageTaken: 23, hits: 0, misses: 1, crits: 0, kills: 0 });
+  });
+
+  it("fans area damage out per resolution", () => {
+    const stats = combatStatsFromEvents([
+      ev("area_save_damage", {
+        actor: "wizard",
+        resolutions: [
+          { target: "a", damage: { applied_total: 9 }, target_hp: 0 },
+          { target: "b", damage: { applied_total: 4 }, target_hp: 6 },
+          { target: "c", damage: { applied_total: 0 }, target_hp: 10 },
+        ],
+      }),
+    ]);
+    expect(stats.wizard).toMatchObject({ damageDealt: 13, kills: 1, hits: 0, misses: 0 });
+    expect(stats.a.damageTaken).toBe(9);
+    expect(stats.c).toBeUndefined();
+  });
+
+  it("takes back blocked damage and the kill when Shield Block saves the target", () => {
+    const stats = combatStatsFromEvents([
+      ev("strike", { actor: "ogre", target: "guard", degree: "success", damage: { total: 9 }, target_hp: 0 }),
+      ev("shield_block", { actor: "guard", hardness: 5, damage_blocked: 5, shield_damage: 4, shield_hp: 16, actor_hp: 5 }),
+    ]);
+    expect(stats.ogre).toMatchObject({ damageDealt: 4, kills: 0, hits: 1 });
+    expect(stats.guard.damageTaken).toBe(4);
+  });
+
+  it("keeps the kill when the blocked damage still leaves the target at 0", () => {
+    const stats = combatStatsFromEvents([
+      ev("strike", { actor: "ogre", target: "guard", degree: "success", damage: { total: 20 }, target_hp: 0 }),
+      ev("shield_block", { actor: "guard", hardness: 5, damage_blocked: 5, shield_damage: 15, shield_hp: 5, actor_hp: 0 }),
+    ]);
+    expect(stats.ogre).toMatchObject({ damageDealt: 15, kills: 1 });
+  });
+
+  it("counts hazard routine results and affliction stage damage", () => {
+    const stats = combatStatsFromEvents([
+      ev("run_hazard_routine", {
+        actor: "trap",
+        results: [
+          { target: "a", damage: { applied_total: 6 }, target_hp: 4 },
+          { target: "b", damage: null, target_hp: 10 },
+        ],
+      }),
+      ev("trigger_hazard_source", { actor: "trap", results: [{ target: "b", damage: { applied_total: 10 }, target_hp: 0 }] }),
+      ev("effect_tick", { kind: "affliction", target: "a", stage_result: { damage: [{ total: 3 }, { total: 2 }] } }),
+    ]);
+    expect(stats.trap).toMatchObject({ damageDealt: 16, kills: 1 });
+    expect(stats.a.damageTaken).toBe(11);
+    expect(stats.b.damageTaken).toBe(10);
+  });
+
+  it("agrees with the HP lost in real reducer events", () => {
+    let state = createCombatBattle();
+    const rng = createTestRNG();
+    const events: Record<string, unknown>[] = [];
+    for (let i = 0; i < 3; i++) {
+      const [next, evs] = applyCommand(state, { type: "strike", actor: "attacker", target: "defender" }, rng);
+      state = next;
+      events.push(...evs);
+      if (state.units.defender.hp === 0) break;
+    }
+    const stats = combatStatsFromEvents(events);
+    const lost = state.units.defender.maxHp - state.units.defender.hp;
+    // applied_total is pre-clamp, so a killing blow can overshoot the HP actually lost
+    expect(stats.attacker.damageDealt).toBeGreaterThanOrEqual(lost);
+    expect(stats.defender?.damageTaken ?? 0).toBe(stats.attacker.damageDealt);
+    expect(stats.attacker.hits + stats.attacker.misses).toBe(events.filter((e) => e["type"] === "strike").length);
+    expect(stats.attacker.kills).toBe(state.units.defender.hp === 0 ? 1 : 0);
+  });
+});
@@ -0,0 +1,138 @@
+/**
+ * Per-unit combat statistics — a read model over the engine event log.
+ *
+ * Nothing here touches the reducer: stats are folded from the same event
+ * payloads the combat log and animation layer already read, so adding a stat
+ * never changes replay hashes. Feed it the full event list of a battle for
+ * an after-action report, or a slice for a single round.
+ */
+
+export interface CombatStats {
+  /**
+   * Damage this unit applied after resistances/weaknesses but before it was
+   * clamped to the target's HP — temp-HP absorption and overkill both count.
+   * Damage the target took back with Shield Block does not.
+   */
+  damageDealt: number;
+  /**
+   * Damage this unit received, measured the same way as damageDealt: strikes,
+   * spells, hazard zones and routines, persistent damage, and affliction stages.
+   */
+  damageTaken: number;
+  /** Attack rolls that succeeded (crits included). */
+  hits: number;
+  /** Attack rolls that failed. */
+  misses: number;
+  /** Attack rolls that critically succeeded. */
+  crits: number;
+  /** Units this unit dropped to 0 HP that stayed there after any Shield Block. */
+  kills: number;
+}
+
+function emptyStats(): CombatStats {
+  return { damageDealt: 0, damageTaken: 0, hits: 0, misses: 0, crits: 0, kills: 0 };
+}
+
+/** Same key precedence as the animation layer: save-based events report `applied_total`. */
+function appliedDamage(payload: Record<string, unknown>): number {
+  const dmg = payload["damage"];
+  if (!dmg || typeof dmg !== "object") return 0;
+  const d = dmg as Record<string, unknown>;
+  return Math.max(0, Number(d["applied_total"] ?? d["total"] ?? 0));
+}
+
+/** Affliction stage damage — a list of rolls, each reporting its post-modifier `total`. */
+function stageDamage(payload: Record<string, unknown>): number {
+  const stage = payload["stage_result"];
+  if (!stage || typeof stage !== "object") return 0;
+  const rolls = (stage as Record<string, unknown>)["damage"];
+  if (!Array.isArray(rolls)) return 0;
+  return rolls.reduce((sum: number, d) => sum + Math.max(0, Number((d as Record<string, unknown>)?.["total"] ?? 0)), 0);
+}
+
+const ATTACK_EVENTS = new Set(["strike", "reaction_strike"]);
+const SINGLE_TARGET_DAMAGE_EVENTS = new Set([
+  "strike", "reaction_strike", "cast_spell", "save_damage", "effect_tick", "hazard_tick",
+]);
+/** Events carrying one damage entry per target; the key holding the list. */
+const MULTI_TARGET_DAMAGE_EVENTS: Readonly<Record<string, string>> = {
+  area_save_damage: "resolutions",
+  run_hazard_routine: "results",
+  trigger_hazard_source: "results",
+};
+/** Affliction events whose stage_result can roll damage (no acting unit in the payload). */
+const AFFLICTION_STAGE_EVENTS = new Set(["effect_apply", "effect_tick"]);
+
+/**
+ * Fold an event log into stats keyed by unitId. Units that never appear in a
+ * relevant event are absent. Damage with no acting unit (hazard ticks,
+ * persistent damage, affliction stages) counts toward damageTaken only.
+ * A shield_block takes its damage_blocked back from the last hit on the
+ * blocker, and the kill with it if the blocker is back above 0 HP.
+ */
+export function combatStatsFromEvents(
+  events: ReadonlyArray<Record<string, unknown>>,
+): Record<string, CombatStats> {
+  const stats: Record<string, CombatStats> = {};
+  const of = (unitId: string) => (stats[unitId] ??= emptyStats());
+  /** Most recent damage each unit took — what a following shield_block reduces. */
+  const lastHit: Record<string, { actor: string; killed: boolean }> = {};
+
+  const recordDamage = (actor: string, target: string, amount: number, targetHp: unknown) => {
+    if (!target || amount <= 0) return;
+    of(target).damageTaken += amount;
+    const killed = !!actor && Number(targetHp) === 0;
+    lastHit[target] = { actor, killed };
+    if (!actor) return;
+    of(actor).damageDealt += amount;
+    if (killed) of(actor).kills += 1;
+  };
+  const recordHit = (actor: string, hit: Record<string, unknown>) =>
+    recordDamage(actor, String(hit["target"] ?? ""), appliedDamage(hit), hit["target_hp"]);
+
+  for (const event of events) {
+    const type = String(event["type"] ?? "");
+    const payload = (event["payload"] as Record<string, unknown>) ?? {};
+    const actor = payload["actor"] != null ? String(payload["actor"]) : "";
+
+    if (ATTACK_EVENTS.has(type) && actor) {
+      const degree = String(payload["degree"] ?? "");
+      const s = of(actor);
+      if (degree === "critical_success") {
+        s.hits += 1;
+        s.crits += 1;
+      } else if (degree === "success") {
+        s.hits += 1;
+      } else {
+        s.misses += 1;
+      }
+    }
+
+    if (SINGLE_TARGET_DAMAGE_EVENTS.has(type)) {
+      recordHit(actor, payload);
+    } else if (type in MULTI_TARGET_DAMAGE_EVENTS) {
+      const list = payload[MULTI_TARGET_DAMAGE_EVENTS[type]];
+      for (const res of Array.isArray(list) ? list : []) {
+        if (res && typeof res === "object") recordHit(actor, res as Record<string, unknown>);
+      }
+    }
+
+    if (AFFLICTION_STAGE_EVENTS.has(type)) {
+      recordDamage("", String(payload["target"] ?? ""), stageDamage(payload), null);
+    }
+
+    if (type === "shield_block" && actor) {
+      const blocked = Math.max(0, Number(payload["damage_blocked"] ?? 0));
+      of(actor).damageTaken -= blocked;
+      const hit = lastHit[actor];
+      if (hit?.actor) {
+        of(hit.actor).damageDealt -= blocked;
+        if (hit.killed && Number(payload["actor_hp"]) > 0) {
+          of(hit.actor).kills -= 1;
+          hit.killed = false;
+        }
+      }
+    }
+  }
+  return stats;
+}
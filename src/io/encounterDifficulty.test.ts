@@ -0,0 +1,62 @@
+import { describe, it, expect } from "vitest";
+import { createShieldUnit, createTestBattle, createTestUnit } from "../test-utils/fixtures";
+import { buildOrchestratorConfig } from "./battleOrchestrator";
+import { estimateDifficulty } from "./encounterDifficulty";
+
+const config = buildOrchestratorConfig({ enemy_policy: { enabled: true, teams: ["enemy"] } });
+
+const trivial = createTestBattle({
+  units: {
+    fighter: createTestUnit({ unitId: "fighter", team: "pc", x: 1, y: 1, hp: 40, maxHp: 40, attackMod: 15, damage: "2d8+6", initiative: 20 }),
+    cleric: createTestUnit({ unitId: "cleric", team: "pc", x: 1, y: 3, hp: 30, maxHp: 30, attackMod: 12, damage: "1d8+4", initiative: 15 }),
+    rat: createTestUnit({ unitId: "rat", team: "enemy", x: 6, y: 2, hp: 6, maxHp: 6, ac: 12, attackMod: 2, damage: "1d3", initiative: 5 }),
+  },
+  turnOrder: ["fighter", "cleric", "rat"],
+});
+
+describe("estimateDifficulty", () => {
+  it("reports a high win rate for a trivial encounter", () => {
+    const report = estimateDifficulty(trivial, config, { runs: 20, maxRounds: 10 });
+    expect(report.runs).toBe(20);
+    expect(report.winRate).toBeGreaterThanOrEqual(0.95);
+    expect(report.victories + report.defeats + report.unresolved).toBe(20);
+    expect(report.avgRounds).toBeLessThanOrEqual(10);
+    expect(report.partyMaxHp).toBe(70);
+    expect(report.avgPartyHpRemaining).toBeGreaterThan(60);
+  });
+
+  it("is reproducible for a fixed base seed", () => {
+    const a = estimateDifficulty(trivial, config, { runs: 10, baseSeed: 42 });
+    const b = estimateDifficulty(trivial, config, { runs: 10, baseSeed: 42 });
+    expect(b).toEqual(a);
+  });
+
+  it("asks acceptReaction about party reactions", () => {
+    const shieldWall = createTestBattle({
+      units: {
+        guard: createShieldUnit({ unitId: "guard", team: "pc", x: 1, y: 1, hp: 200, maxHp: 200, initiative: 20 }),
+        brute: createTestUnit({ unitId: "brute", team: "enemy", x: 2, y: 1, hp: 60, maxHp: 60, attackMod: 12, damage: "1d6+4", initiative: 10 }),
+      },
+      turnOrder: ["guard", "brute"],
+    });
+    let asked = 0;
+    const report = estimateDifficulty(shieldWall, config, {
+      runs: 3,
+      maxRounds: 2,
+      // Raise the shield, then wait for the brute
+      partyPolicy: (state) => state.units.guard.actionsRemaining === 3
+        ? { type: "raise_shield", actor: "guard" }
+        : { type: "end_turn", actor: "guard" },
+      acceptReaction: (trigger) => {
+        asked++;
+        return trigger.reactionType === "shield_block";
+      },
+    });
+    expect(asked).toBeGreaterThan(0);
+    expect(report.avgRounds).toBe(2);
+  });
+
+  it("rejects a non-positive run count", () => {
+    expect(() => estimateDifficulty(trivial, config, { runs: 0 })).toThrow(/runs/);
+  });
+});
@@ -0,0 +1,79 @@
+/**
+ * Encounter balance report — runs a battle many times with simulateBattle
+ * and summarises how the party fares.
+ *
+ * Each run reseeds the battle (state.seed = baseSeed + i) with a fresh RNG,
+ * so a report is fully reproducible from its inputs. Content authors can
+ * tune an encounter by comparing reports instead of playtesting by hand.
+ *
+ * Reactions are resolved as in simulateBattle: enemies always take theirs,
+ * and the party takes every reaction unless `acceptReaction` declines — so
+ * by default the report assumes a party that never misses a Shield Block.
+ */
+
+import { DeterministicRNG } from "../engine/rng";
+import { BattleState } from "../engine/state";
+import { type OrchestratorConfig } from "./battleOrchestrator";
+import { type SimulationOptions, simulateBattle } from "./battleSimulation";
+
+export interface DifficultyOptions extends SimulationOptions {
+  /** Number of simulated battles. Default 50. */
+  runs?: number;
+  /** Seed of the first run; run i uses baseSeed + i. Default 1. */
+  baseSeed?: number;
+}
+
+export interface DifficultyReport {
+  runs: number;
+  victories: number;
+  defeats: number;
+  /** Draws plus runs stopped by the round cap. */
+  unresolved: number;
+  /** victories / runs, 0..1. */
+  winRate: number;
+  /** Mean rounds played; a capped run counts as maxRounds. */
+  avgRounds: number;
+  /** Mean of total party HP left at the end of each run. */
+  avgPartyHpRemaining: number;
+  /** Total party max HP at the start — the denominator for avgPartyHpRemaining. */
+  partyMaxHp: number;
+}
+
+function partyUnits(state: BattleState, config: OrchestratorConfig) {
+  return Object.values(state.units).filter((u) => config.playerTeams.includes(u.team));
+}
+
+export function estimateDifficulty(
+  state: BattleState,
+  config: OrchestratorConfig,
+  options: DifficultyOptions = {},
+): DifficultyReport {
+  const runs = options.runs ?? 50;
+  const baseSeed = options.baseSeed ?? 1;
+  if (!Number.isInteger(runs) || runs < 1) throw new Error(`runs must be a positive integer, got ${runs}`);
+
+  let victories = 0;
+  let defeats = 0;
+  let totalRounds = 0;
+  let totalHp = 0;
+
+  for (let i = 0; i < runs; i++) {
+    const seed = baseSeed + i;
+    const result = simulateBattle({ ...state, seed }, config, new DeterministicRNG(seed), options);
+    if (result.outcome === "victory") victories++;
+    else if (result.outcome === "defeat") defeats++;
+    totalRounds += result.rounds;
+    totalHp += partyUnits(result.state, config).reduce((sum, u) => sum + Math.max(0, u.hp), 0);
+  }
+
+  return {
+    runs,
+    victories,
+    defeats,
+    unresolved: runs - victories - defeats,
+    winRate: victories / runs,
+    avgRounds: totalRounds / runs,
+    avgPartyHpRemaining: totalHp / runs,
+    partyMaxHp: partyUnits(state, config).reduce((sum, u) => sum + u.maxHp, 0),
+  };
+}
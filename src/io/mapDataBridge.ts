@@ -327,6 +327,42 @@ export function extractObjectives(tiledMap: ResolvedTiledMap): ObjectiveData[] {
   return objectives;
 }
 
+// ---------------------------------------------------------------------------
+// applyTiledTerrain
+// ---------------------------------------------------------------------------
+
+/**
+ * Hybrid scenarios (hand-written JSON with a `tiled_map` reference): replace
+ * the scenario's `map` block with the Tiled map's terrain and hazard zones.
+ * Units, objectives, content packs, and enemy policy stay as written.
+ */
+export function applyTiledTerrain(
+  scenario: Record<string, unknown>,
+  tiledMap: ResolvedTiledMap,
+): Record<string, unknown> {
+  const tiledMapState = extractMapState(tiledMap);
+  const tiledHazards = extractHazardZones(tiledMap);
+  return {
+    ...scenario,
+    map: {
+      width: tiledMapState.width,
+      height: tiledMapState.height,
+      blocked: tiledMapState.blocked,
+      ...(tiledMapState.moveCost   && { move_cost:    tiledMapState.moveCost }),
+      ...(tiledMapState.coverGrade && { cover_grade:  tiledMapState.coverGrade }),
+      ...(tiledMapState.elevation  && { elevation:    tiledMapState.elevation }),
+      ...(tiledHazards.length > 0  && { hazards: tiledHazards.map((z) => ({
+        id: z.id,
+        damage_type: z.element,
+        damage_per_turn: z.damagePerTurn,
+        dc: z.dc,
+        save_type: z.saveType,
+        tiles: z.tiles,
+      })) }),
+    },
+  };
+}
+
 // ---------------------------------------------------------------------------
 // buildScenarioFromTiledMap
 // ---------------------------------------------------------------------------
@@ -402,7 +438,7 @@ export function buildScenarioFromTiledMap(tiledMap: ResolvedTiledMap): Record<st
       ...(mapState.moveCost && { move_cost: mapState.moveCost }),
       ...(mapState.coverGrade && { cover_grade: mapState.coverGrade }),
       ...(mapState.elevation && { elevation: mapState.elevation }),
-      // Shape matches the tiled_map hybrid path in scenarioLoader.ts and the
+      // Shape matches applyTiledTerrain (the tiled_map hybrid path) and the
       // validateScenario schema for map.hazards (id, damage_type,
       // damage_per_turn, dc, save_type, tiles).
       ...(hazards.length > 0 && { hazards: hazards.map((z) => ({
@@ -0,0 +1,88 @@
+/**
+ * Save-compatibility defaults for BattleState.
+ *
+ * A save written by an older build is missing any UnitState / BattleState
+ * field added since. Rather than bumping the save version for every new
+ * field, optional-with-a-sensible-default fields are filled in here on load.
+ * Defaults match what `battleStateFromScenario` assigns when the scenario
+ * JSON omits the field, so a restored unit behaves like a freshly loaded one.
+ *
+ * When adding a field to UnitState or BattleState: if it's required and has
+ * a neutral default, add it to `unitDefaults` / `battleDefaults` below and
+ * extend the legacy fixture test. If it has no neutral default, bump the
+ * save version instead.
+ */
+
+import type { BattleState, UnitState } from "../engine/state";
+
+/** Fields a save must carry — there is no neutral default for these. */
+const REQUIRED_UNIT_FIELDS = [
+  "team", "hp", "maxHp", "x", "y", "initiative", "attackMod", "ac", "damage",
+] as const;
+
+/** Fresh objects per call so restored units never share default arrays/records. */
+function unitDefaults(): Omit<UnitState, "unitId" | (typeof REQUIRED_UNIT_FIELDS)[number]> {
+  return {
+    tempHp: 0,
+    tempHpSource: null,
+    tempHpOwnerEffectId: null,
+    attackDamageType: "physical",
+    attackDamageBypass: [],
+    fortitude: 0,
+    reflex: 0,
+    will: 0,
+    actionsRemaining: 3,
+    reactionAvailable: true,
+    speed: 5,
+    reach: 1,
+    attacksThisTurn: 0,
+    conditions: {},
+    conditionImmunities: [],
+    resistances: {},
+    weaknesses: {},
+    immunities: [],
+    abilitiesRemaining: {},
+  };
+}
+
+function battleDefaults(): Pick<BattleState, "roundNumber" | "turnIndex" | "effects" | "flags" | "eventSequence"> {
+  return {
+    roundNumber: 1,
+    turnIndex: 0,
+    effects: {},
+    flags: {},
+    eventSequence: 0,
+  };
+}
+
+/**
+ * Fill missing optional fields on a deserialized BattleState. Fields that are
+ * present are kept as-is. Returns null when the blob is missing something
+ * that can't be defaulted (ids, positions, core stats, map dimensions, turn
+ * order) — callers treat that the same as "no save".
+ */
+export function restoreBattleState(raw: unknown): BattleState | null {
+  if (raw === null || typeof raw !== "object") return null;
+  const data = raw as Partial<BattleState>;
+  if (typeof data.battleId !== "string" || typeof data.seed !== "number") return null;
+  if (!Array.isArray(data.turnOrder) || !data.units || typeof data.units !== "object") return null;
+  const map = data.battleMap;
+  if (!map || typeof map.width !== "number" || typeof map.height !== "number") return null;
+
+  const units: Record<string, UnitState> = {};
+  for (const [id, u] of Object.entries(data.units)) {
+    if (!u || typeof u !== "object") return null;
+    if (REQUIRED_UNIT_FIELDS.some((f) => (u as unknown as Record<string, unknown>)[f] === undefined)) return null;
+    units[id] = { ...unitDefaults(), ...u, unitId: u.unitId ?? id };
+  }
+
+  return {
+    ...battleDefaults(),
+    ...data,
+    battleId: data.battleId,
+    seed: data.seed,
+    turnOrder: data.turnOrder,
+    units,
+    battleMap: { ...map, blocked: map.blocked ?? [] },
+  } as BattleState;
+}
@@ -427,6 +427,27 @@ export function validateScenario(data: Record<string, unknown>): void {
     typeof mapData["height"] === "number" && Number.isInteger(mapData["height"]) && Number(mapData["height"]) > 0,
     "map.height must be positive int",
   );
+  const mapWidth = Number(mapData["width"]);
+  const mapHeight = Number(mapData["height"]);
+  // Out-of-bounds tiles would otherwise be silently ignored — almost always a typo'd coordinate.
+  const requireOnMap = (x: number, y: number, context: string) =>
+    require(
+      x >= 0 && x < mapWidth && y >= 0 && y < mapHeight,
+      `${context} (${x}, ${y}) is outside the ${mapWidth}x${mapHeight} map`,
+    );
+
+  const mapBlocked = (mapData["blocked"] as unknown[]) ?? [];
+  require(Array.isArray(mapBlocked), "map.blocked must be list when present");
+  for (let idx = 0; idx < mapBlocked.length; idx++) {
+    const tile = mapBlocked[idx];
+    require(
+      Array.isArray(tile) && tile.length === 2 &&
+        Number.isInteger(tile[0]) && Number.isInteger(tile[1]),
+      `map.blocked[${idx}] must be [int, int] pair`,
+    );
+    const [bx, by] = tile as [number, number];
+    requireOnMap(bx, by, `map.blocked[${idx}]`);
+  }
 
   const mapHazards = (mapData["hazards"] as unknown[]) ?? [];
   require(Array.isArray(mapHazards), "map.hazards must be list when present");
@@ -455,6 +476,8 @@ export function validateScenario(data: Record<string, unknown>): void {
           Number.isInteger(tile[0]) && Number.isInteger(tile[1]),
         `map.hazards[${idx}] tile must be [int, int] pair`,
       );
+      const [hx, hy] = tile as [number, number];
+      requireOnMap(hx, hy, `map.hazards[${idx}] tile`);
     }
   }
 
@@ -887,29 +910,9 @@ export async function loadScenarioFromUrl(url: string): Promise<LoadScenarioResu
   if (typeof data["tiled_map"] === "string" && data["tiled_map"]) {
     const tiledMapPath = data["tiled_map"] as string;
     const { loadTiledMap } = await import("./tiledLoader");
-    const { extractMapState, extractHazardZones } = await import("./mapDataBridge");
+    const { applyTiledTerrain } = await import("./mapDataBridge");
     const tiledMap = await loadTiledMap(tiledMapPath);
-    const tiledMapState = extractMapState(tiledMap);
-    const tiledHazards = extractHazardZones(tiledMap);
-    const mergedData: Record<string, unknown> = {
-      ...data,
-      map: {
-        width: tiledMapState.width,
-        height: tiledMapState.height,
-        blocked: tiledMapState.blocked,
-        ...(tiledMapState.moveCost   && { move_cost:    tiledMapState.moveCost }),
-        ...(tiledMapState.coverGrade && { cover_grade:  tiledMapState.coverGrade }),
-        ...(tiledMapState.elevation  && { elevation:    tiledMapState.elevation }),
-        ...(tiledHazards.length > 0  && { hazards: tiledHazards.map((z) => ({
-          id: z.id,
-          damage_type: z.element,
-          damage_per_turn: z.damagePerTurn,
-          dc: z.dc,
-          save_type: z.saveType,
-          tiles: z.tiles,
-        })) }),
-      },
-    };
+    const mergedData = applyTiledTerrain(data, tiledMap);
     validateScenario(mergedData);
     const enginePhase = (mergedData["engine_phase"] as number) ?? 7;
     const contentContext = await resolveScenarioContentContext(mergedData, enginePhase);
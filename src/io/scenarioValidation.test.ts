@@ -91,6 +91,27 @@ describe("Scenario Structure Validation", () => {
     expect(() => validateScenario(scenario)).toThrow(ScenarioValidationError);
   });
 
+  test("accepts blocked tiles on the map edge", () => {
+    const scenario = baseScenario();
+    (scenario["map"] as Record<string, unknown>)["blocked"] = [[0, 0], [5, 5]];
+    expect(() => validateScenario(scenario)).not.toThrow();
+  });
+
+  test("rejects blocked tile outside the map", () => {
+    const scenario = baseScenario();
+    (scenario["map"] as Record<string, unknown>)["blocked"] = [[1, 1], [6, 2]];
+    expect(() => validateScenario(scenario)).toThrow("map.blocked[1] (6, 2) is outside the 6x6 map");
+  });
+
+  test("rejects hazard tile outside the map", () => {
+    const scenario = baseScenario();
+    (scenario["map"] as Record<string, unknown>)["hazards"] = [{
+      id: "fire", damage_type: "fire", damage_per_turn: 2, dc: 15, save_type: "Reflex",
+      tiles: [[0, -1]],
+    }];
+    expect(() => validateScenario(scenario)).toThrow(/map\.hazards\[0\] tile \(0, -1\) is outside/);
+  });
+
   test("rejects empty units list", () => {
     const scenario = baseScenario();
     scenario["units"] = [];
@@ -11,6 +11,7 @@ import { hasLineOfSight } from "../grid/los";
 import { hasTileLineOfEffect } from "../grid/loe";
 import { radiusPoints } from "../grid/areas";
 import { inBounds, tilesFromFeet } from "../grid/map";
+import { chebyshevDistance } from "../grid/movement";
 import { thrownRange } from "../engine/traits";
 import { TILE_SIZE } from "./pixiApp";
 
@@ -160,7 +161,7 @@ export function showStrikeTargets(state: BattleState, actorId: string, weaponInd
     if (unit.unitId === actorId) continue;
     if (!unitAlive(unit)) continue;
     if (unit.team === actor.team) continue;
-    const dist = Math.max(Math.abs(unit.x - actor.x), Math.abs(unit.y - actor.y));
+    const dist = chebyshevDistance(unit.x, unit.y, actor.x, actor.y);
     if (!hasLineOfSight(state, actor, unit)) continue;
 
     if (weapon.type === "melee") {
@@ -5,6 +5,7 @@
 
 import { Container, Graphics } from "pixi.js";
 import { MapState } from "../engine/state";
+import { mapTiles } from "../grid/map";
 import { TILE_SIZE } from "./pixiApp";
 
 const FLOOR_COLOR = 0x2d2d44;
@@ -36,12 +37,10 @@ export function renderTileMap(parent: Container, mapState: MapState): void {
   const g = new Graphics();
 
   // Draw floor tiles
-  for (let ty = 0; ty < mapState.height; ty++) {
-    for (let tx = 0; tx < mapState.width; tx++) {
-      const isBlocked = blockedSet.has(`${tx},${ty}`);
-      const color = isBlocked ? BLOCKED_COLOR : FLOOR_COLOR;
-      g.rect(tx * TILE_SIZE, ty * TILE_SIZE, TILE_SIZE - 1, TILE_SIZE - 1).fill(color);
-    }
+  for (const [tx, ty] of mapTiles(mapState)) {
+    const isBlocked = blockedSet.has(`${tx},${ty}`);
+    const color = isBlocked ? BLOCKED_COLOR : FLOOR_COLOR;
+    g.rect(tx * TILE_SIZE, ty * TILE_SIZE, TILE_SIZE - 1, TILE_SIZE - 1).fill(color);
   }
 
   // Draw grid lines
@@ -35,10 +35,11 @@ import {
   materializeRawCommand,
 } from "../io/battleOrchestrator";
 import { loadScenarioFromUrl } from "../io/scenarioLoader";
-import { type ReactionTrigger, detectMoveReactions, detectDamageReactions } from "../engine/reactions";
+import { type ReactionTrigger, detectCommandReactions, reactionCommand } from "../engine/reactions";
 import type { CampaignDefinition, CampaignProgress } from "../campaign/campaignTypes";
 import { snapshotParty, applyPartySnapshot, healPartyAtCamp, resetAbilitiesForBattle } from "../campaign/campaignState";
 import { writeCampaignSave, readCampaignSave, clearCampaignSave } from "../campaign/campaignPersistence";
+import { restoreBattleState } from "../io/saveMigration";
 
 // ---------------------------------------------------------------------------
 // Re-export useful types for consumers
@@ -89,7 +90,10 @@ function readSave(): SavedGame | null {
     const parsed = JSON.parse(raw) as SavedGame;
     if (parsed.version !== 1) return null;
     if (typeof parsed.rngCallCount !== "number" || parsed.rngCallCount < 0 || parsed.rngCallCount > 100_000) return null;
-    return parsed;
+    // Saves from older builds lack fields added since — fill defaults rather than reject.
+    const battle = restoreBattleState(parsed.battle);
+    if (!battle) return null;
+    return { ...parsed, battle };
   } catch {
     return null;
   }
@@ -564,52 +568,8 @@ export const useBattleStore = create<BattleStore>()((set, get) => ({
         transient.animationQueue.push(...animations);
 
         if (!ended) {
-          // Detect reaction triggers after move/strike commands
-          const cmdType = String(cmd["type"] ?? "");
-          let triggers: ReactionTrigger[] = [];
-
-          if (cmdType === "move") {
-            // fromX/fromY from the move event
-            const moveEvent = newEvents.find(e => e["type"] === "move");
-            if (moveEvent) {
-              const payload = moveEvent["payload"] as Record<string, unknown>;
-              const from = payload["from"] as number[];
-              if (from) {
-                triggers = detectMoveReactions(nextState, String(cmd["actor"]), from[0], from[1]);
-              }
-            }
-          } else if (cmdType === "strike" || cmdType === "reaction_strike" ||
-                     cmdType === "cast_spell" || cmdType === "save_damage") {
-            const damageEvent = newEvents.find(e =>
-              e["type"] === cmdType || e["type"] === "strike" || e["type"] === "reaction_strike");
-            if (damageEvent) {
-              const payload = damageEvent["payload"] as Record<string, unknown>;
-              const dmg = payload["damage"] as Record<string, unknown> | null;
-              if (dmg && Number(dmg["applied_total"] ?? dmg["total"] ?? 0) > 0) {
-                const targetId = String(payload["target"]);
-                const damageType = String(dmg["damage_type"] ?? "physical");
-                const total = Number(dmg["applied_total"] ?? dmg["total"]);
-                triggers = detectDamageReactions(nextState, targetId, total, damageType);
-              }
-            }
-          } else if (cmdType === "area_save_damage") {
-            const areaEvent = newEvents.find(e => e["type"] === "area_save_damage");
-            if (areaEvent) {
-              const payload = areaEvent["payload"] as Record<string, unknown>;
-              const resolutions = payload["resolutions"] as Record<string, unknown>[];
-              if (resolutions) {
-                for (const res of resolutions) {
-                  const dmg = res["damage"] as Record<string, unknown> | null;
-                  if (dmg && Number(dmg["applied_total"] ?? 0) > 0) {
-                    const targetId = String(res["target"]);
-                    const damageType = String(dmg["damage_type"] ?? "physical");
-                    const t = detectDamageReactions(nextState, targetId, Number(dmg["applied_total"]), damageType);
-                    triggers.push(...t);
-                  }
-                }
-              }
-            }
-          }
+          // Detect reaction triggers after move/strike/damage commands
+          const triggers = detectCommandReactions(nextState, cmd, newEvents);
 
           if (triggers.length > 0) {
             // Prepend, don't replace — a nested reaction dispatch (inside
@@ -851,21 +811,7 @@ export const useBattleStore = create<BattleStore>()((set, get) => ({
       set({ pendingReaction: null });
 
       if (accept) {
-        let cmd: Record<string, unknown> | null = null;
-        if (pendingReaction.reactionType === "attack_of_opportunity" ||
-            pendingReaction.reactionType === "reactive_strike") {
-          cmd = {
-            type: "reaction_strike",
-            actor: pendingReaction.reactorId,
-            target: pendingReaction.provokerId,
-          };
-        } else if (pendingReaction.reactionType === "shield_block") {
-          cmd = {
-            type: "shield_block",
-            actor: pendingReaction.reactorId,
-            damage_amount: Number(pendingReaction.data?.["damageAmount"] ?? 0),
-          };
-        }
+        const cmd = reactionCommand(pendingReaction);
         if (cmd) {
           get().dispatchCommand(cmd);
           // dispatchCommand's success tail calls _processNextReaction, which
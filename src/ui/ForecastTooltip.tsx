@@ -15,6 +15,7 @@ import { useBattleStore } from "../store/battleStore";
 import { activeUnitId, unitAlive, resolveWeapon } from "../engine/state";
 import { strikeForecast } from "../engine/forecast";
 import { adjustCoverForMelee, coverAcBonusFromGrade, coverGradeForUnits } from "../grid/loe";
+import { chebyshevDistance } from "../grid/movement";
 import { isAgile, mapPenalty, volleyPenalty, deadlyDice, fatalDice, thrownRange } from "../engine/traits";
 
 function pct(n: number): string {
@@ -47,7 +48,7 @@ export function ForecastTooltip() {
     return null;
   }
 
-  const dist = Math.max(Math.abs(actor.x - target.x), Math.abs(actor.y - target.y));
+  const dist = chebyshevDistance(actor.x, actor.y, target.x, target.y);
 
   // Ammo gate — don't show forecast when weapon has no ammo
   if (weapon.ammo != null) {